@@ -0,0 +1,702 @@
+//! Generates `src/generated.rs` from the vendored Raydium locking IDL.
+//!
+//! The IDL (Anchor 0.30 format) is the source of truth for instruction
+//! discriminators, account order and flags, arguments, state account layouts
+//! and program error codes. The tables below only hold what the IDL cannot
+//! express: Rust names kept for API stability and remaining-account limits.
+
+use std::fmt::Write;
+
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+/// IDL location, relative to the repository root.
+pub const IDL_PATH: &str = "idl/raydium_liquidity_locking.json";
+
+/// Generated module location, relative to the repository root.
+pub const GENERATED_PATH: &str = "src/generated.rs";
+
+// Struct names that differ from the UpperCamelCase IDL instruction name
+const INSTRUCTION_NAMES: &[(&str, &str)] = &[
+    ("collect_clmm_fees_and_rewards", "CollectClmmFeeAndReward"),
+    ("collect_cp_fees", "CollectCpFee"),
+];
+
+// (instruction, IDL account name, field name) for renamed accounts
+const ACCOUNT_NAMES: &[(&str, &str, &str)] =
+    &[("collect_cp_fees", "cp_swap_program", "cpmm_program")];
+
+// Instructions forwarding extra accounts, with the maximum they accept
+const REMAINING_ACCOUNTS: &[(&str, &str)] = &[(
+    "collect_clmm_fees_and_rewards",
+    "crate::instructions::MAX_REMAINING_ACCOUNTS",
+)];
+
+const HEADER: &str = "\
+//! Lock program bindings generated from `idl/raydium_liquidity_locking.json`.
+//!
+//! Do not edit by hand: run `cargo run -p pinocchio-raydium-locking-codegen`
+//! after updating the IDL. `tests/idl.rs` fails when this file is stale.
+";
+
+/// Renders the generated module for `idl`, an Anchor IDL JSON document.
+pub fn generate(idl: &str) -> Result<String, String> {
+    let idl: Value = serde_json::from_str(idl).map_err(|e| format!("invalid IDL JSON: {e}"))?;
+
+    let instructions = array(&idl, "instructions")?;
+    let accounts = array(&idl, "accounts")?;
+    let errors = array(&idl, "errors")?;
+    let types = array(&idl, "types")?;
+
+    let mut items = String::new();
+    let mut client = String::new();
+    let mut decode = String::new();
+    for instruction in instructions {
+        items.push('\n');
+        if !client.is_empty() {
+            client.push('\n');
+            decode.push('\n');
+        }
+        instruction_item(&mut items, &mut client, &mut decode, instruction)?;
+    }
+
+    if !client.is_empty() {
+        items.push_str(
+            "\n/// Off-chain builders for the instructions above, with one pubkey field per\n\
+             /// account; re-exported from `crate::client`.\n\
+             #[cfg(feature = \"client\")]\n\
+             pub(crate) mod client {\n    \
+             use crate::client::client_instruction;\n\n",
+        );
+        items.push_str(&client);
+        items.push_str("}\n");
+    }
+
+    if !decode.is_empty() {
+        items.push_str(
+            "\n/// Typed views of a decoded instruction's accounts, with one accessor per\n\
+             /// account; re-exported from `crate::decode`.\n\
+             pub(crate) mod decode {\n    \
+             use crate::decode::decoded_accounts;\n\n",
+        );
+        items.push_str(&decode);
+        items.push_str("}\n");
+    }
+
+    for account in accounts {
+        let name = str_field(account, "name")?;
+        let ty = types
+            .iter()
+            .find(|ty| ty["name"] == name)
+            .ok_or_else(|| format!("no type definition for account `{name}`"))?;
+        items.push('\n');
+        let discriminator = discriminator(account, &format!("account:{name}"))?;
+        account_item(&mut items, name, &discriminator, ty)?;
+    }
+
+    items.push('\n');
+    errors_item(&mut items, errors)?;
+
+    let mut imports = Vec::new();
+    if items.contains("anchor_discriminator(") {
+        imports.push("discriminator::anchor_discriminator");
+    }
+    if !instructions.is_empty() {
+        imports.push("instructions::declare_cpi");
+    }
+
+    let mut out = String::from(HEADER);
+    out.push('\n');
+    if !accounts.is_empty() {
+        out.push_str("use pinocchio::pubkey::Pubkey;\n\n");
+    }
+    match imports.as_slice() {
+        [] => {}
+        [import] => writeln!(out, "use crate::{import};").unwrap(),
+        imports => writeln!(out, "use crate::{{{}}};", imports.join(", ")).unwrap(),
+    }
+    out.push_str(&items);
+
+    Ok(out)
+}
+
+// Writes the instruction's `declare_cpi!` to `out`, its off-chain builder to
+// `client` and its decoded account view to `decode`, all from the same
+// account list
+fn instruction_item(
+    out: &mut String,
+    client: &mut String,
+    decode: &mut String,
+    instruction: &Value,
+) -> Result<(), String> {
+    let idl_name = str_field(instruction, "name")?;
+    let name = INSTRUCTION_NAMES
+        .iter()
+        .find(|(idl, _)| *idl == idl_name)
+        .map_or_else(|| upper_camel_case(idl_name), |(_, name)| name.to_string());
+
+    out.push_str("declare_cpi! {\n");
+    client.push_str("    client_instruction! {\n");
+    for line in summary(instruction) {
+        writeln!(out, "    /// {line}").unwrap();
+        writeln!(client, "        /// {line}").unwrap();
+    }
+    writeln!(out, "    pub struct {name} {{").unwrap();
+    writeln!(client, "        pub struct {name} {{").unwrap();
+    decode.push_str("    decoded_accounts! {\n");
+    writeln!(
+        decode,
+        "        /// Accounts of a decoded `{name}`, by name."
+    )
+    .unwrap();
+    writeln!(decode, "        pub struct {name}Accounts for {name} {{").unwrap();
+    let discriminator = discriminator(instruction, &format!("global:{idl_name}"))?;
+    writeln!(out, "        discriminator: {discriminator},").unwrap();
+
+    out.push_str("        accounts: [\n");
+    client.push_str("            accounts: [\n");
+    for (index, account) in array(instruction, "accounts")?.iter().enumerate() {
+        let account_name = str_field(account, "name")?;
+        let field = ACCOUNT_NAMES
+            .iter()
+            .find(|(ix, idl, _)| *ix == idl_name && *idl == account_name)
+            .map_or(account_name, |(_, _, field)| field);
+        let role = match (flag(account, "signer"), flag(account, "writable")) {
+            (false, false) => "readonly",
+            (false, true) => "writable",
+            (true, false) => "readonly_signer",
+            (true, true) => "writable_signer",
+        };
+        writeln!(out, "            {field}: {role},").unwrap();
+        writeln!(client, "                {field},").unwrap();
+        writeln!(decode, "            {field}: {index},").unwrap();
+    }
+    out.push_str("        ],\n");
+    client.push_str("            ],\n");
+
+    let args = array(instruction, "args")?;
+    if args.is_empty() {
+        writeln!(out, "        args: {name}Args {{}},").unwrap();
+        writeln!(client, "            args: {name}Args {{}},").unwrap();
+    } else {
+        writeln!(out, "        args: {name}Args {{").unwrap();
+        writeln!(client, "            args: {name}Args {{").unwrap();
+        for arg in args {
+            let arg_name = str_field(arg, "name")?;
+            let ty = arg_type(&arg["type"])
+                .ok_or_else(|| format!("unsupported type for argument `{idl_name}.{arg_name}`"))?;
+            writeln!(out, "            {arg_name}: {ty},").unwrap();
+            writeln!(client, "                {arg_name}: {ty},").unwrap();
+        }
+        out.push_str("        },\n");
+        client.push_str("            },\n");
+    }
+
+    if let Some((_, max)) = REMAINING_ACCOUNTS.iter().find(|(ix, _)| *ix == idl_name) {
+        writeln!(out, "        remaining_accounts: {max},").unwrap();
+        client.push_str("            remaining_accounts,\n");
+    }
+
+    out.push_str("    }\n}\n");
+    client.push_str("        }\n    }\n");
+    decode.push_str("        }\n    }\n");
+    Ok(())
+}
+
+fn account_item(
+    out: &mut String,
+    name: &str,
+    discriminator: &str,
+    ty: &Value,
+) -> Result<(), String> {
+    if ty["type"]["kind"] != "struct" {
+        return Err(format!("account `{name}` is not a struct"));
+    }
+
+    let mut fields = Vec::new();
+    let mut len = 8;
+    for field in array(&ty["type"], "fields")? {
+        let field_name = str_field(field, "name")?;
+        let kind = FieldKind::parse(&field["type"])
+            .ok_or_else(|| format!("unsupported type for field `{name}.{field_name}`"))?;
+        len += kind.len();
+        fields.push((field_name, docs(field), kind));
+    }
+
+    writeln!(
+        out,
+        "/// Borsh layout of the lock program's `{name}` account."
+    )
+    .unwrap();
+    out.push_str(
+        "///\n\
+         /// Every field is stored as a byte array so the struct has an alignment of 1\n\
+         /// and matches the on-chain data byte for byte; use the accessors to read it.\n\
+         #[repr(C)]\n\
+         #[derive(Clone, Copy, Debug, PartialEq)]\n",
+    );
+    writeln!(out, "pub struct {name} {{").unwrap();
+    for (field_name, _, kind) in &fields {
+        writeln!(out, "    {field_name}: {},", kind.storage()).unwrap();
+    }
+    out.push_str("}\n\n");
+
+    writeln!(out, "const _: () = assert!({name}::LEN == {len});\n").unwrap();
+
+    writeln!(out, "impl {name} {{").unwrap();
+    out.push_str("    pub const LEN: usize = core::mem::size_of::<Self>() + 8;\n\n");
+    writeln!(
+        out,
+        "    pub const DISCRIMINATOR: [u8; 8] = {discriminator};"
+    )
+    .unwrap();
+
+    // Offsets into the account data, e.g. for `getProgramAccounts` memcmp filters
+    for (field_name, _, kind) in &fields {
+        if kind.setter(field_name).is_none() {
+            continue;
+        }
+        let upper = field_name.to_uppercase();
+        let value = format!("8 + core::mem::offset_of!(Self, {field_name});");
+        writeln!(
+            out,
+            "\n    /// Offset of `{field_name}` from the start of the account data."
+        )
+        .unwrap();
+        let item = format!("    pub const {upper}_OFFSET: usize = {value}");
+        if item.len() <= 100 {
+            writeln!(out, "{item}").unwrap();
+        } else {
+            writeln!(
+                out,
+                "    pub const {upper}_OFFSET: usize =\n        {value}"
+            )
+            .unwrap();
+        }
+    }
+
+    for (field_name, docs, kind) in &fields {
+        let Some((ty, body)) = kind.accessor(field_name) else {
+            continue;
+        };
+        out.push('\n');
+        for line in docs {
+            writeln!(out, "    /// {line}").unwrap();
+        }
+        out.push_str("    #[inline(always)]\n");
+        writeln!(out, "    pub fn {field_name}(&self) -> {ty} {{").unwrap();
+        writeln!(out, "        {body}").unwrap();
+        out.push_str("    }\n");
+    }
+    out.push_str("}\n\n");
+
+    // Mutable view and setters for building fixtures and the mock program
+    writeln!(out, "#[cfg(feature = \"test-utils\")]\nimpl {name} {{").unwrap();
+    out.push_str(
+        "    /// # Safety\n\
+         \x20   ///\n\
+         \x20   /// Same requirements as `from_bytes`, and no other borrow of `bytes`\n\
+         \x20   /// may be live.\n\
+         \x20   #[inline(always)]\n\
+         \x20   pub unsafe fn from_bytes_mut(bytes: &mut [u8]) -> &mut Self {\n\
+         \x20       &mut *(bytes.as_mut_ptr() as *mut Self)\n\
+         \x20   }\n",
+    );
+    for (field_name, _, kind) in &fields {
+        let Some((ty, body)) = kind.setter(field_name) else {
+            continue;
+        };
+        out.push_str("\n    #[inline(always)]\n");
+        writeln!(
+            out,
+            "    pub fn set_{field_name}(&mut self, value: {ty}) {{"
+        )
+        .unwrap();
+        writeln!(out, "        {body}").unwrap();
+        out.push_str("    }\n");
+    }
+
+    out.push_str("\n    /// Starts an account fixture with every field zeroed.\n");
+    writeln!(out, "    pub fn builder() -> {name}Builder {{").unwrap();
+    writeln!(out, "        {name}Builder {{").unwrap();
+    out.push_str("            state: Self {\n");
+    for (field_name, _, kind) in &fields {
+        writeln!(out, "                {field_name}: {},", kind.zero()).unwrap();
+    }
+    out.push_str("            },\n        }\n    }\n}\n\n");
+
+    builder_item(out, name, &fields);
+    Ok(())
+}
+
+fn builder_item(out: &mut String, name: &str, fields: &[(&str, Vec<&str>, FieldKind)]) {
+    writeln!(
+        out,
+        "/// Builds `{name}` account data for tests; unset fields stay zero."
+    )
+    .unwrap();
+    out.push_str(
+        "#[cfg(feature = \"test-utils\")]\n\
+         #[derive(Clone, Copy, Debug, PartialEq)]\n",
+    );
+    writeln!(out, "pub struct {name}Builder {{\n    state: {name},\n}}\n").unwrap();
+
+    writeln!(
+        out,
+        "#[cfg(feature = \"test-utils\")]\nimpl {name}Builder {{"
+    )
+    .unwrap();
+    for (field_name, _, kind) in fields {
+        let Some((ty, _)) = kind.setter(field_name) else {
+            continue;
+        };
+        writeln!(
+            out,
+            "    pub fn {field_name}(mut self, value: {ty}) -> Self {{"
+        )
+        .unwrap();
+        writeln!(out, "        self.state.set_{field_name}(value);").unwrap();
+        out.push_str("        self\n    }\n\n");
+    }
+
+    out.push_str("    /// The account fields as built.\n");
+    writeln!(
+        out,
+        "    pub fn build(&self) -> {name} {{\n        self.state\n    }}\n"
+    )
+    .unwrap();
+
+    out.push_str(
+        "    /// Complete account data: the Anchor discriminator followed by the\n\
+         \x20   /// fields, `LEN` bytes in total.\n",
+    );
+    writeln!(
+        out,
+        "    pub fn to_account_bytes(&self) -> std::vec::Vec<u8> {{\n\
+         \x20       let mut data = std::vec::Vec::with_capacity({name}::LEN);\n\
+         \x20       data.extend_from_slice(&{name}::DISCRIMINATOR);\n\
+         \x20       // SAFETY: the struct is plain bytes with an alignment of 1.\n\
+         \x20       data.extend_from_slice(unsafe {{\n\
+         \x20           core::slice::from_raw_parts(\n\
+         \x20               &self.state as *const {name} as *const u8,\n\
+         \x20               core::mem::size_of::<{name}>(),\n\
+         \x20           )\n\
+         \x20       }});\n\
+         \x20       data\n\
+         \x20   }}\n"
+    )
+    .unwrap();
+
+    out.push_str("    /// A writable, rent-exempt account at `key` owned by the lock program.\n");
+    writeln!(
+        out,
+        "    pub fn to_test_account(&self, key: Pubkey) -> crate::test_utils::TestAccount {{\n\
+         \x20       let lamports = crate::test_utils::rent_exempt_lamports({name}::LEN);\n\
+         \x20       let data = self.to_account_bytes();\n\
+         \x20       crate::test_utils::TestAccount::new(key, crate::ID, lamports, &data)\
+         .writable()\n\
+         \x20   }}\n\
+         }}"
+    )
+    .unwrap();
+}
+
+fn errors_item(out: &mut String, errors: &[Value]) -> Result<(), String> {
+    let mut variants = Vec::new();
+    for error in errors {
+        let name = str_field(error, "name")?;
+        let code = error["code"]
+            .as_u64()
+            .and_then(|code| u32::try_from(code).ok())
+            .ok_or_else(|| format!("error `{name}` has no code"))?;
+        let msg = error["msg"].as_str().unwrap_or(name);
+        variants.push((upper_camel_case(name), code, msg));
+    }
+
+    out.push_str(
+        "/// Errors defined by the lock program's `#[error_code]` enum, with codes from\n\
+         /// `ANCHOR_ERROR_CODE_OFFSET` on.\n\
+         #[derive(Clone, Copy, Debug, PartialEq, Eq)]\n",
+    );
+    if variants.is_empty() {
+        out.push_str("pub enum ErrorCode {}\n");
+    } else {
+        out.push_str("pub enum ErrorCode {\n");
+        for (name, _, msg) in &variants {
+            writeln!(out, "    /// {msg}\n    {name},").unwrap();
+        }
+        out.push_str("}\n");
+    }
+
+    out.push_str("\nimpl ErrorCode {\n    /// Every error, in code order.\n");
+    let all: Vec<String> = variants
+        .iter()
+        .map(|(name, ..)| format!("Self::{name}"))
+        .collect();
+    let item = format!(
+        "    pub const ALL: [Self; {}] = [{}];",
+        all.len(),
+        all.join(", ")
+    );
+    if item.len() <= 100 {
+        writeln!(out, "{item}").unwrap();
+    } else {
+        writeln!(out, "    pub const ALL: [Self; {}] = [", all.len()).unwrap();
+        for variant in &all {
+            writeln!(out, "        {variant},").unwrap();
+        }
+        out.push_str("    ];\n");
+    }
+
+    out.push_str("\n    /// Custom error code as returned by the program.\n");
+    match_item(
+        out,
+        "code",
+        "u32",
+        variants.iter().map(|(n, c, _)| (n, c.to_string())),
+    );
+    out.push_str("\n    /// The error's `#[msg]`, or its name when it has none.\n");
+    match_item(
+        out,
+        "message",
+        "&'static str",
+        variants.iter().map(|(n, _, m)| (n, format!("{m:?}"))),
+    );
+    out.push_str("}\n");
+    Ok(())
+}
+
+// `const fn {name}(self) -> {ty}` matching every variant to its value
+fn match_item<'a>(
+    out: &mut String,
+    name: &str,
+    ty: &str,
+    arms: impl ExactSizeIterator<Item = (&'a String, String)>,
+) {
+    writeln!(out, "    pub const fn {name}(self) -> {ty} {{").unwrap();
+    if arms.len() == 0 {
+        out.push_str("        match self {}\n    }\n");
+        return;
+    }
+    out.push_str("        match self {\n");
+    for (variant, value) in arms {
+        let arm = format!("            Self::{variant} => {value},");
+        if arm.len() <= 100 {
+            writeln!(out, "{arm}").unwrap();
+        } else {
+            writeln!(
+                out,
+                "            Self::{variant} => {{\n                {value}\n            }}"
+            )
+            .unwrap();
+        }
+    }
+    out.push_str("        }\n    }\n");
+}
+
+// Storage of a state account field in the generated alignment-1 struct
+enum FieldKind {
+    U8,
+    Bool,
+    Int(&'static str, usize),
+    Pubkey,
+    // Anchor's `[u8; 1]` bump, kept as an array so it can be used as a seed
+    Bump,
+    Bytes(usize),
+}
+
+impl FieldKind {
+    fn parse(ty: &Value) -> Option<Self> {
+        if let Some(name) = ty.as_str() {
+            return match name {
+                "u8" => Some(Self::U8),
+                "bool" => Some(Self::Bool),
+                "pubkey" => Some(Self::Pubkey),
+                _ => Some(Self::Int(int_name(name)?, int_size(name)?)),
+            };
+        }
+
+        let [element, len] = ty["array"].as_array()?.as_slice() else {
+            return None;
+        };
+        let len = len.as_u64()? as usize;
+        match element.as_str()? {
+            "u8" if len == 1 => Some(Self::Bump),
+            element => Some(Self::Bytes(primitive_size(element)? * len)),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Self::U8 | Self::Bool | Self::Bump => 1,
+            Self::Int(_, size) => *size,
+            Self::Pubkey => 32,
+            Self::Bytes(len) => *len,
+        }
+    }
+
+    fn storage(&self) -> String {
+        match self {
+            Self::U8 | Self::Bool => "u8".to_string(),
+            Self::Pubkey => "Pubkey".to_string(),
+            _ => format!("[u8; {}]", self.len()),
+        }
+    }
+
+    // Zero value of the field's storage
+    fn zero(&self) -> String {
+        match self {
+            Self::U8 | Self::Bool => "0".to_string(),
+            _ => format!("[0; {}]", self.len()),
+        }
+    }
+
+    // Argument type and body of the field's setter; padding has none
+    fn setter(&self, field: &str) -> Option<(String, String)> {
+        if field.starts_with("padding") {
+            return None;
+        }
+        Some(match self {
+            Self::U8 => ("u8".to_string(), format!("self.{field} = value;")),
+            Self::Bool => ("bool".to_string(), format!("self.{field} = value as u8;")),
+            Self::Int(ty, _) => (
+                ty.to_string(),
+                format!("self.{field} = value.to_le_bytes();"),
+            ),
+            Self::Pubkey => ("Pubkey".to_string(), format!("self.{field} = value;")),
+            Self::Bump => ("u8".to_string(), format!("self.{field} = [value];")),
+            Self::Bytes(len) => (format!("[u8; {len}]"), format!("self.{field} = value;")),
+        })
+    }
+
+    // Return type and body of the field's accessor; padding has none
+    fn accessor(&self, field: &str) -> Option<(String, String)> {
+        if field.starts_with("padding") {
+            return None;
+        }
+        Some(match self {
+            Self::U8 => ("u8".to_string(), format!("self.{field}")),
+            Self::Bool => ("bool".to_string(), format!("self.{field} != 0")),
+            Self::Int(ty, _) => (ty.to_string(), format!("{ty}::from_le_bytes(self.{field})")),
+            Self::Pubkey => ("&Pubkey".to_string(), format!("&self.{field}")),
+            Self::Bump => ("u8".to_string(), format!("self.{field}[0]")),
+            Self::Bytes(len) => (format!("&[u8; {len}]"), format!("&self.{field}")),
+        })
+    }
+}
+
+fn arg_type(ty: &Value) -> Option<&'static str> {
+    match ty.as_str()? {
+        "bool" => Some("bool"),
+        name => int_name(name),
+    }
+}
+
+fn int_name(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "u8" => "u8",
+        "u16" => "u16",
+        "u32" => "u32",
+        "u64" => "u64",
+        "u128" => "u128",
+        "i8" => "i8",
+        "i16" => "i16",
+        "i32" => "i32",
+        "i64" => "i64",
+        "i128" => "i128",
+        _ => return None,
+    })
+}
+
+fn int_size(name: &str) -> Option<usize> {
+    Some(match name {
+        "u8" | "i8" => 1,
+        "u16" | "i16" => 2,
+        "u32" | "i32" => 4,
+        "u64" | "i64" => 8,
+        "u128" | "i128" => 16,
+        _ => return None,
+    })
+}
+
+fn primitive_size(name: &str) -> Option<usize> {
+    match name {
+        "bool" => Some(1),
+        "pubkey" => Some(32),
+        _ => int_size(name),
+    }
+}
+
+// Leading doc lines of an IDL item, up to its `# Arguments` section
+fn summary(item: &Value) -> Vec<&str> {
+    docs(item)
+        .into_iter()
+        .take_while(|line| !line.starts_with('#') && !line.is_empty())
+        .collect()
+}
+
+fn docs(item: &Value) -> Vec<&str> {
+    item["docs"]
+        .as_array()
+        .map(|docs| {
+            docs.iter()
+                .filter_map(Value::as_str)
+                .map(str::trim)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// Discriminator expression for `item`: derived from `preimage` when the IDL
+// bytes are Anchor's default, the literal bytes otherwise
+fn discriminator(item: &Value, preimage: &str) -> Result<String, String> {
+    let bytes = array(item, "discriminator")?
+        .iter()
+        .map(|byte| byte.as_u64().and_then(|byte| u8::try_from(byte).ok()))
+        .collect::<Option<Vec<u8>>>();
+    let bytes = match bytes {
+        Some(bytes) if bytes.len() == 8 => bytes,
+        _ => return Err(format!("invalid discriminator for `{}`", item["name"])),
+    };
+
+    if Sha256::digest(preimage)[..8] == bytes[..] {
+        Ok(format!("anchor_discriminator({preimage:?})"))
+    } else {
+        Ok(byte_array(&bytes))
+    }
+}
+
+fn byte_array(bytes: &[u8]) -> String {
+    let bytes: Vec<String> = bytes.iter().map(|byte| format!("0x{byte:02x}")).collect();
+    format!("[{}]", bytes.join(", "))
+}
+
+fn array<'a>(item: &'a Value, key: &str) -> Result<&'a [Value], String> {
+    match &item[key] {
+        Value::Null => Ok(&[]),
+        value => value
+            .as_array()
+            .map(Vec::as_slice)
+            .ok_or_else(|| format!("`{key}` is not an array")),
+    }
+}
+
+fn str_field<'a>(item: &'a Value, key: &str) -> Result<&'a str, String> {
+    item[key].as_str().ok_or_else(|| format!("missing `{key}`"))
+}
+
+fn flag(item: &Value, key: &str) -> bool {
+    item[key].as_bool().unwrap_or_default()
+}
+
+fn upper_camel_case(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
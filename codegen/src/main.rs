@@ -0,0 +1,28 @@
+//! Regenerates `src/generated.rs` from `idl/raydium_liquidity_locking.json`.
+
+use std::{fs, path::Path, process::ExitCode};
+
+use pinocchio_raydium_locking_codegen::{generate, GENERATED_PATH, IDL_PATH};
+
+fn main() -> ExitCode {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+
+    let generated = fs::read_to_string(root.join(IDL_PATH))
+        .map_err(|e| format!("failed to read {IDL_PATH}: {e}"))
+        .and_then(|idl| generate(&idl))
+        .and_then(|generated| {
+            fs::write(root.join(GENERATED_PATH), generated)
+                .map_err(|e| format!("failed to write {GENERATED_PATH}: {e}"))
+        });
+
+    match generated {
+        Ok(()) => {
+            println!("wrote {GENERATED_PATH}");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
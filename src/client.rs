@@ -0,0 +1,343 @@
+//! Off-chain builders for the lock program instructions.
+//!
+//! The builders mirror the CPI structs in [`crate::instructions`] with plain
+//! pubkeys and reuse their account tables and data encoders, so the accounts
+//! and bytes produced here match what the on-chain path sends.
+
+use core::mem::MaybeUninit;
+use std::vec::Vec;
+
+use pinocchio::pubkey::Pubkey;
+
+use crate::{
+    instructions::AccountRole,
+    pda,
+    programs::{
+        ASSOCIATED_TOKEN_PROGRAM_ID, CLMM_PROGRAM_ID, CPMM_PROGRAM_ID, MEMO_PROGRAM_ID,
+        METADATA_PROGRAM_ID, RENT_SYSVAR_ID, SYSTEM_PROGRAM_ID, TOKEN_2022_PROGRAM_ID,
+        TOKEN_PROGRAM_ID,
+    },
+    raydium::{
+        clmm::{PersonalPositionState, PoolState},
+        cpmm,
+    },
+    states::{LockedClmmPositionState, LockedCpLiquidityState},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountMeta {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Instruction {
+    pub program_id: Pubkey,
+    pub accounts: Vec<AccountMeta>,
+    pub data: Vec<u8>,
+}
+
+impl Instruction {
+    // Pairs each `ACCOUNTS` role with the key in the same position; the client
+    // builders list their keys from the same account list as the table.
+    pub(crate) fn new<const N: usize>(
+        roles: &[AccountRole; N],
+        keys: [Pubkey; N],
+        data: &[MaybeUninit<u8>],
+    ) -> Self {
+        let accounts = roles
+            .iter()
+            .zip(keys)
+            .map(|(role, pubkey)| AccountMeta {
+                pubkey,
+                is_signer: role.is_signer,
+                is_writable: role.is_writable,
+            })
+            .collect();
+
+        Self {
+            program_id: crate::ID,
+            accounts,
+            // SAFETY: the instruction encoders initialize every byte of `data`.
+            data: data
+                .iter()
+                .map(|byte| unsafe { byte.assume_init() })
+                .collect(),
+        }
+    }
+}
+
+/// Declares a client builder with one pubkey field per instruction account
+/// and one field per argument, from the account list the instruction's
+/// `declare_cpi!` was generated from.
+///
+/// Generates `key`/`key_mut` lookups by the names in the instruction's
+/// `ACCOUNTS` table and `instruction`. Ending the spec with
+/// `remaining_accounts` adds a `remaining_accounts` field appended after the
+/// fixed accounts.
+macro_rules! client_instruction {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident {
+            accounts: [$($account:ident),* $(,)?],
+            args: $args:ident { $($arg:ident: $arg_ty:ty),* $(,)? } $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, Default, PartialEq, Eq)]
+        pub struct $name {
+            $(pub $account: ::pinocchio::pubkey::Pubkey,)*
+            $(pub $arg: $arg_ty,)*
+        }
+
+        $crate::client::client_instruction!(@common $name, [$($account),*], $args [$($arg),*]);
+
+        impl $name {
+            /// The instruction with this builder's accounts and arguments.
+            pub fn instruction(&self) -> $crate::client::Instruction {
+                self.fixed_instruction()
+            }
+        }
+    };
+
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident {
+            accounts: [$($account:ident),* $(,)?],
+            args: $args:ident { $($arg:ident: $arg_ty:ty),* $(,)? },
+            remaining_accounts $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, Default, PartialEq, Eq)]
+        pub struct $name {
+            $(pub $account: ::pinocchio::pubkey::Pubkey,)*
+            $(pub $arg: $arg_ty,)*
+
+            /// Accounts appended after the fixed ones, with their own flags.
+            pub remaining_accounts: ::std::vec::Vec<$crate::client::AccountMeta>,
+        }
+
+        $crate::client::client_instruction!(@common $name, [$($account),*], $args [$($arg),*]);
+
+        impl $name {
+            /// The instruction with this builder's accounts and arguments,
+            /// followed by `remaining_accounts`.
+            pub fn instruction(&self) -> $crate::client::Instruction {
+                let mut instruction = self.fixed_instruction();
+                instruction.accounts.extend_from_slice(&self.remaining_accounts);
+                instruction
+            }
+        }
+    };
+
+    (@common $name:ident, [$($account:ident),*], $args:ident [$($arg:ident),*]) => {
+        impl $name {
+            /// Key of the instruction account called `name`, as in `ACCOUNTS`.
+            pub fn key(&self, name: &str) -> Option<&::pinocchio::pubkey::Pubkey> {
+                match name {
+                    $(stringify!($account) => Some(&self.$account),)*
+                    _ => None,
+                }
+            }
+
+            /// Mutable key of the instruction account called `name`, e.g. to
+            /// override a derived account.
+            pub fn key_mut(&mut self, name: &str) -> Option<&mut ::pinocchio::pubkey::Pubkey> {
+                match name {
+                    $(stringify!($account) => Some(&mut self.$account),)*
+                    _ => None,
+                }
+            }
+
+            fn fixed_instruction(&self) -> $crate::client::Instruction {
+                let mut data = [$crate::UNINIT_BYTE; $crate::instructions::$name::DATA_LEN];
+                $crate::instructions::$name::write_data(
+                    &mut data,
+                    &$crate::instructions::$args { $($arg: self.$arg,)* },
+                );
+
+                $crate::client::Instruction::new(
+                    &$crate::instructions::$name::ACCOUNTS,
+                    [$(self.$account),*],
+                    &data,
+                )
+            }
+        }
+    };
+}
+
+pub(crate) use client_instruction;
+
+pub use crate::generated::client::{
+    CollectClmmFeeAndReward, CollectCpFee, LockClmmPosition, LockCpLiquidity,
+};
+
+#[cfg(feature = "solana-instruction")]
+impl From<AccountMeta> for solana_instruction::AccountMeta {
+    fn from(meta: AccountMeta) -> Self {
+        Self {
+            pubkey: solana_pubkey::Pubkey::new_from_array(meta.pubkey),
+            is_signer: meta.is_signer,
+            is_writable: meta.is_writable,
+        }
+    }
+}
+
+#[cfg(feature = "solana-instruction")]
+impl From<Instruction> for solana_instruction::Instruction {
+    fn from(instruction: Instruction) -> Self {
+        Self {
+            program_id: solana_pubkey::Pubkey::new_from_array(instruction.program_id),
+            accounts: instruction.accounts.into_iter().map(Into::into).collect(),
+            data: instruction.data,
+        }
+    }
+}
+
+impl LockCpLiquidity {
+    /// Fills every account derivable from the CPMM pool at `pool_state` and
+    /// the fee NFT mint, locking from `liquidity_owner`'s LP associated
+    /// token account.
+    ///
+    /// `with_metadata` is left `false`; set it to also create the fee NFT's
+    /// metadata account.
+    pub fn new(
+        pool_state: Pubkey,
+        pool: &cpmm::PoolState,
+        payer: Pubkey,
+        liquidity_owner: Pubkey,
+        fee_nft_owner: Pubkey,
+        fee_nft_mint: Pubkey,
+        lp_amount: u64,
+    ) -> Self {
+        let lp_mint = *pool.lp_mint();
+
+        Self {
+            authority: pda::find_lock_cp_authority().0,
+            payer,
+            liquidity_owner,
+            fee_nft_owner,
+            fee_nft_mint,
+            fee_nft_account: pda::find_fee_nft_account(
+                &fee_nft_owner,
+                &fee_nft_mint,
+                &TOKEN_PROGRAM_ID,
+            )
+            .0,
+            pool_state,
+            locked_liquidity: pda::find_locked_liquidity(&fee_nft_mint).0,
+            lp_mint,
+            liquidity_owner_lp: pda::find_associated_token_account(
+                &liquidity_owner,
+                &lp_mint,
+                &TOKEN_PROGRAM_ID,
+            )
+            .0,
+            locked_lp_vault: pda::find_locked_lp_vault(&lp_mint, &TOKEN_PROGRAM_ID).0,
+            token_0_vault: *pool.token_0_vault(),
+            token_1_vault: *pool.token_1_vault(),
+            metadata_account: pda::find_metadata_account(&fee_nft_mint).0,
+            rent: RENT_SYSVAR_ID,
+            system_program: SYSTEM_PROGRAM_ID,
+            token_program: TOKEN_PROGRAM_ID,
+            associated_token_program: ASSOCIATED_TOKEN_PROGRAM_ID,
+            metadata_program: METADATA_PROGRAM_ID,
+            lp_amount,
+            with_metadata: false,
+        }
+    }
+}
+
+impl CollectCpFee {
+    /// Fills every account derivable from the locked liquidity and its CPMM
+    /// pool, including the vault mints and the locked LP vault.
+    pub fn new(
+        locked_liquidity: &LockedCpLiquidityState,
+        pool: &cpmm::PoolState,
+        fee_nft_owner: Pubkey,
+        recipient_token_0_account: Pubkey,
+        recipient_token_1_account: Pubkey,
+        fee_lp_amount: u64,
+    ) -> Self {
+        let fee_nft_mint = locked_liquidity.fee_nft_mint();
+
+        Self {
+            authority: pda::find_lock_cp_authority().0,
+            fee_nft_owner,
+            fee_nft_account: pda::find_fee_nft_account(
+                &fee_nft_owner,
+                fee_nft_mint,
+                &TOKEN_PROGRAM_ID,
+            )
+            .0,
+            locked_liquidity: pda::find_locked_liquidity(fee_nft_mint).0,
+            cpmm_program: CPMM_PROGRAM_ID,
+            cp_authority: pda::find_cpmm_authority().0,
+            pool_state: *locked_liquidity.pool_id(),
+            lp_mint: *pool.lp_mint(),
+            recipient_token_0_account,
+            recipient_token_1_account,
+            token_0_vault: *pool.token_0_vault(),
+            token_1_vault: *pool.token_1_vault(),
+            vault_0_mint: *pool.token_0_mint(),
+            vault_1_mint: *pool.token_1_mint(),
+            locked_lp_vault: pda::find_locked_lp_vault(pool.lp_mint(), &TOKEN_PROGRAM_ID).0,
+            token_program: TOKEN_PROGRAM_ID,
+            token_program_2022: TOKEN_2022_PROGRAM_ID,
+            memo_program: MEMO_PROGRAM_ID,
+            fee_lp_amount,
+        }
+    }
+}
+
+impl CollectClmmFeeAndReward {
+    /// Fills every account derivable from the locked position, its CLMM pool
+    /// and personal position, including tick arrays and protocol position.
+    ///
+    /// `remaining_accounts` is left empty; push the reward triples for the
+    /// pool's initialized rewards to collect them too. Returns `None` if the
+    /// pool's `tick_spacing` is zero.
+    pub fn new(
+        locked_position: &LockedClmmPositionState,
+        pool: &PoolState,
+        personal_position: &PersonalPositionState,
+        fee_nft_owner: Pubkey,
+        fee_nft_token_program: &Pubkey,
+        recipient_token_0_account: Pubkey,
+        recipient_token_1_account: Pubkey,
+    ) -> Option<Self> {
+        let fee_nft_mint = locked_position.fee_nft_mint();
+        let position_pdas = personal_position.position_pdas(pool.tick_spacing())?;
+
+        Some(Self {
+            authority: pda::find_lock_clmm_authority().0,
+            fee_nft_owner,
+            fee_nft_account: pda::find_fee_nft_account(
+                &fee_nft_owner,
+                fee_nft_mint,
+                fee_nft_token_program,
+            )
+            .0,
+            locked_position: pda::find_locked_position(fee_nft_mint).0,
+            clmm_program: CLMM_PROGRAM_ID,
+            locked_nft_account: *locked_position.locked_nft_account(),
+            personal_position: *locked_position.position_id(),
+            pool_state: *locked_position.pool_id(),
+            protocol_position: position_pdas.protocol_position,
+            token_0_vault: *pool.token_vault_0(),
+            token_1_vault: *pool.token_vault_1(),
+            tick_array_lower: position_pdas.tick_array_lower,
+            tick_array_upper: position_pdas.tick_array_upper,
+            recipient_token_0_account,
+            recipient_token_1_account,
+            token_program: TOKEN_PROGRAM_ID,
+            token_program_2022: TOKEN_2022_PROGRAM_ID,
+            memo_program: MEMO_PROGRAM_ID,
+            vault_0_mint: *pool.token_mint_0(),
+            vault_1_mint: *pool.token_mint_1(),
+            remaining_accounts: Vec::new(),
+        })
+    }
+}
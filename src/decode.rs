@@ -0,0 +1,184 @@
+use crate::{
+    error::LockingError,
+    instructions::{
+        AccountRole, CollectClmmFeeAndReward, CollectClmmFeeAndRewardArgs, CollectCpFee,
+        CollectCpFeeArgs, LockClmmPosition, LockClmmPositionArgs, LockCpLiquidity,
+        LockCpLiquidityArgs,
+    },
+};
+
+pub use crate::generated::decode::{
+    CollectClmmFeeAndRewardAccounts, CollectCpFeeAccounts, LockClmmPositionAccounts,
+    LockCpLiquidityAccounts,
+};
+
+/// A lock program instruction with its arguments, parsed from instruction data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LockingInstruction {
+    LockCpLiquidity { lp_amount: u64, with_metadata: bool },
+    LockClmmPosition { with_metadata: bool },
+    CollectCpFee { fee_lp_amount: u64 },
+    CollectClmmFeeAndReward,
+}
+
+impl LockingInstruction {
+    pub fn unpack(data: &[u8]) -> Result<Self, LockingError> {
+        let (discriminator, args) = data
+            .split_first_chunk::<8>()
+            .ok_or(LockingError::InvalidInstructionData)?;
+
+        match *discriminator {
+            LockCpLiquidity::DISCRIMINATOR => {
+                let LockCpLiquidityArgs {
+                    lp_amount,
+                    with_metadata,
+                } = LockCpLiquidityArgs::decode(args)?;
+                Ok(Self::LockCpLiquidity {
+                    lp_amount,
+                    with_metadata,
+                })
+            }
+            LockClmmPosition::DISCRIMINATOR => {
+                let LockClmmPositionArgs { with_metadata } = LockClmmPositionArgs::decode(args)?;
+                Ok(Self::LockClmmPosition { with_metadata })
+            }
+            CollectCpFee::DISCRIMINATOR => {
+                let CollectCpFeeArgs { fee_lp_amount } = CollectCpFeeArgs::decode(args)?;
+                Ok(Self::CollectCpFee { fee_lp_amount })
+            }
+            CollectClmmFeeAndReward::DISCRIMINATOR => {
+                CollectClmmFeeAndRewardArgs::decode(args)?;
+                Ok(Self::CollectClmmFeeAndReward)
+            }
+            _ => Err(LockingError::UnknownInstructionDiscriminator),
+        }
+    }
+
+    /// Account table of the instruction, in the order the lock program expects.
+    pub fn accounts(&self) -> &'static [AccountRole] {
+        match self {
+            Self::LockCpLiquidity { .. } => &LockCpLiquidity::ACCOUNTS,
+            Self::LockClmmPosition { .. } => &LockClmmPosition::ACCOUNTS,
+            Self::CollectCpFee { .. } => &CollectCpFee::ACCOUNTS,
+            Self::CollectClmmFeeAndReward => &CollectClmmFeeAndReward::ACCOUNTS,
+        }
+    }
+}
+
+/// A decoded instruction paired with its account list.
+///
+/// `A` is whatever the caller holds for each account, e.g. `AccountInfo`,
+/// `Pubkey` or an introspected account meta.
+#[derive(Clone, Copy, Debug)]
+pub struct DecodedInstruction<'a, A> {
+    pub instruction: LockingInstruction,
+    pub accounts: &'a [A],
+}
+
+impl<'a, A> DecodedInstruction<'a, A> {
+    /// The accounts of a `LockCpLiquidity` by name, or `None` for another
+    /// instruction.
+    pub fn lock_cp_liquidity(&self) -> Option<LockCpLiquidityAccounts<'a, A>> {
+        match self.instruction {
+            LockingInstruction::LockCpLiquidity { .. } => {
+                LockCpLiquidityAccounts::new(self.accounts)
+            }
+            _ => None,
+        }
+    }
+
+    /// The accounts of a `LockClmmPosition` by name, or `None` for another
+    /// instruction.
+    pub fn lock_clmm_position(&self) -> Option<LockClmmPositionAccounts<'a, A>> {
+        match self.instruction {
+            LockingInstruction::LockClmmPosition { .. } => {
+                LockClmmPositionAccounts::new(self.accounts)
+            }
+            _ => None,
+        }
+    }
+
+    /// The accounts of a `CollectCpFee` by name, or `None` for another
+    /// instruction.
+    pub fn collect_cp_fee(&self) -> Option<CollectCpFeeAccounts<'a, A>> {
+        match self.instruction {
+            LockingInstruction::CollectCpFee { .. } => CollectCpFeeAccounts::new(self.accounts),
+            _ => None,
+        }
+    }
+
+    /// The fixed accounts of a `CollectClmmFeeAndReward` by name, or `None`
+    /// for another instruction; see `remaining_accounts` for the rest.
+    pub fn collect_clmm_fee_and_reward(&self) -> Option<CollectClmmFeeAndRewardAccounts<'a, A>> {
+        match self.instruction {
+            LockingInstruction::CollectClmmFeeAndReward => {
+                CollectClmmFeeAndRewardAccounts::new(self.accounts)
+            }
+            _ => None,
+        }
+    }
+
+    /// Iterates over the instruction accounts together with their roles.
+    pub fn named_accounts(&self) -> impl Iterator<Item = (&'static AccountRole, &'a A)> {
+        self.instruction.accounts().iter().zip(self.accounts.iter())
+    }
+
+    /// Accounts passed after the ones listed in the account table.
+    pub fn remaining_accounts(&self) -> &'a [A] {
+        self.accounts
+            .get(self.instruction.accounts().len()..)
+            .unwrap_or(&[])
+    }
+}
+
+/// Decodes lock program instruction data and maps `accounts` to their roles.
+pub fn decode_instruction<'a, A>(
+    data: &[u8],
+    accounts: &'a [A],
+) -> Result<DecodedInstruction<'a, A>, LockingError> {
+    let instruction = LockingInstruction::unpack(data)?;
+
+    if accounts.len() < instruction.accounts().len() {
+        return Err(LockingError::NotEnoughAccountKeys);
+    }
+
+    Ok(DecodedInstruction {
+        instruction,
+        accounts,
+    })
+}
+
+// Declares the typed view over a decoded instruction's accounts, with one
+// accessor per `ACCOUNTS` entry returning the account at its index.
+macro_rules! decoded_accounts {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident for $instruction:ident {
+            $($account:ident: $index:literal),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug)]
+        pub struct $name<'a, A> {
+            accounts: &'a [A; $crate::instructions::$instruction::ACCOUNTS.len()],
+        }
+
+        impl<'a, A> $name<'a, A> {
+            /// Views the first accounts of `accounts`, or `None` if there are
+            /// fewer than the instruction takes.
+            #[inline(always)]
+            pub(crate) fn new(accounts: &'a [A]) -> Option<Self> {
+                accounts.first_chunk().map(|accounts| Self { accounts })
+            }
+
+            $(
+                #[inline(always)]
+                pub fn $account(&self) -> &'a A {
+                    &self.accounts[$index]
+                }
+            )*
+        }
+    };
+}
+
+pub(crate) use decoded_accounts;
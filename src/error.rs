@@ -0,0 +1,400 @@
+use pinocchio::program_error::ProgramError;
+
+pub use crate::generated::ErrorCode;
+
+/// Errors returned by this crate's own checks, surfaced as `ProgramError::Custom`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LockingError {
+    /// Account data does not start with the expected Anchor account discriminator.
+    InvalidAccountDiscriminator,
+    /// Instruction data does not start with a known lock program discriminator.
+    UnknownInstructionDiscriminator,
+    /// Instruction data is too short or holds an invalid argument.
+    InvalidInstructionData,
+    /// Fewer accounts than the instruction requires.
+    NotEnoughAccountKeys,
+    /// The transaction does not lock the required liquidity.
+    LiquidityNotLocked,
+    /// An account that must sign the instruction is not a signer.
+    AccountNotSigner,
+    /// An account that must be writable is not writable.
+    AccountNotWritable,
+    /// A program or sysvar account does not hold the expected address.
+    IncorrectProgramId,
+    /// An account does not match the address derived from its seeds.
+    InvalidPda,
+    /// An account is not owned by the expected program.
+    InvalidAccountOwner,
+    /// Account data does not have the expected length.
+    InvalidAccountLength,
+    /// More accounts than the instruction accepts.
+    TooManyAccounts,
+}
+
+/// Offset of `LockingError` codes ("LK" in the upper half), chosen clear of the
+/// low codes native programs use and of Anchor's framework and program codes.
+pub const LOCKING_ERROR_CODE_OFFSET: u32 = 0x4c4b_0000;
+
+// Every `LockingError`, indexed by its offset into `LOCKING_ERROR_CODE_OFFSET`.
+const LOCKING_ERRORS: [LockingError; 12] = {
+    use LockingError::*;
+    [
+        InvalidAccountDiscriminator,
+        UnknownInstructionDiscriminator,
+        InvalidInstructionData,
+        NotEnoughAccountKeys,
+        LiquidityNotLocked,
+        AccountNotSigner,
+        AccountNotWritable,
+        IncorrectProgramId,
+        InvalidPda,
+        InvalidAccountOwner,
+        InvalidAccountLength,
+        TooManyAccounts,
+    ]
+};
+
+impl LockingError {
+    /// Decodes a custom error code returned by this crate's checks.
+    pub fn from_code(code: u32) -> Option<Self> {
+        let index = code.checked_sub(LOCKING_ERROR_CODE_OFFSET)?;
+        LOCKING_ERRORS.get(index as usize).copied()
+    }
+
+    /// Decodes a `ProgramError::Custom` returned by this crate's checks.
+    pub fn from_program_error(error: &ProgramError) -> Option<Self> {
+        match error {
+            ProgramError::Custom(code) => Self::from_code(*code),
+            _ => None,
+        }
+    }
+
+    /// Custom error code carried by the `ProgramError`.
+    pub const fn code(&self) -> u32 {
+        LOCKING_ERROR_CODE_OFFSET + *self as u32
+    }
+
+    /// Short message for logs.
+    pub fn message(&self) -> &'static str {
+        use LockingError::*;
+        match self {
+            InvalidAccountDiscriminator => "Account discriminator did not match",
+            UnknownInstructionDiscriminator => "Unknown lock program instruction",
+            InvalidInstructionData => "Invalid lock program instruction data",
+            NotEnoughAccountKeys => "Not enough account keys given to the instruction",
+            LiquidityNotLocked => "The transaction does not lock the required liquidity",
+            AccountNotSigner => "An account that must sign did not sign",
+            AccountNotWritable => "An account that must be writable is not writable",
+            IncorrectProgramId => "A program or sysvar account has the wrong address",
+            InvalidPda => "An account does not match its derived address",
+            InvalidAccountOwner => "An account is owned by the wrong program",
+            InvalidAccountLength => "Account data has the wrong length",
+            TooManyAccounts => "More accounts than the instruction accepts",
+        }
+    }
+}
+
+impl From<LockingError> for ProgramError {
+    fn from(e: LockingError) -> Self {
+        ProgramError::Custom(e.code())
+    }
+}
+
+/// Preflight validation failure, naming the offending account role.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValidationError {
+    NotSigner(&'static str),
+    NotWritable(&'static str),
+    IncorrectProgramId(&'static str),
+    InvalidPda(&'static str),
+    TooManyAccounts(&'static str),
+}
+
+impl ValidationError {
+    /// Role name of the offending account, e.g. `"locked_lp_vault"`.
+    pub fn account(&self) -> &'static str {
+        match self {
+            Self::NotSigner(name)
+            | Self::NotWritable(name)
+            | Self::IncorrectProgramId(name)
+            | Self::InvalidPda(name)
+            | Self::TooManyAccounts(name) => name,
+        }
+    }
+}
+
+impl From<ValidationError> for LockingError {
+    fn from(e: ValidationError) -> Self {
+        match e {
+            ValidationError::NotSigner(_) => LockingError::AccountNotSigner,
+            ValidationError::NotWritable(_) => LockingError::AccountNotWritable,
+            ValidationError::IncorrectProgramId(_) => LockingError::IncorrectProgramId,
+            ValidationError::InvalidPda(_) => LockingError::InvalidPda,
+            ValidationError::TooManyAccounts(_) => LockingError::TooManyAccounts,
+        }
+    }
+}
+
+impl From<ValidationError> for ProgramError {
+    fn from(e: ValidationError) -> Self {
+        LockingError::from(e).into()
+    }
+}
+
+/// Offset of program-defined Anchor error codes (`#[error_code]` enums).
+pub const ANCHOR_ERROR_CODE_OFFSET: u32 = 6000;
+
+/// Error returned by the deployed Raydium locking program, decoded from its
+/// `ProgramError::Custom` code.
+///
+/// The lock program is an Anchor program, so most failures caused by wrong
+/// accounts or instruction data are Anchor framework errors with stable codes.
+/// Codes from `ANCHOR_ERROR_CODE_OFFSET` on are defined by the lock program
+/// itself: those in its IDL decode to [`ErrorCode`], any others are kept as
+/// their offset into its error enum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LockProgramError {
+    InstructionMissing,
+    InstructionFallbackNotFound,
+    InstructionDidNotDeserialize,
+    InstructionDidNotSerialize,
+    ConstraintMut,
+    ConstraintHasOne,
+    ConstraintSigner,
+    ConstraintRaw,
+    ConstraintOwner,
+    ConstraintRentExempt,
+    ConstraintSeeds,
+    ConstraintExecutable,
+    ConstraintAssociated,
+    ConstraintAssociatedInit,
+    ConstraintClose,
+    ConstraintAddress,
+    ConstraintZero,
+    ConstraintTokenMint,
+    ConstraintTokenOwner,
+    ConstraintMintMintAuthority,
+    ConstraintMintFreezeAuthority,
+    ConstraintMintDecimals,
+    ConstraintSpace,
+    ConstraintAccountIsNone,
+    ConstraintTokenTokenProgram,
+    ConstraintMintTokenProgram,
+    ConstraintAssociatedTokenTokenProgram,
+    RequireViolated,
+    RequireEqViolated,
+    RequireKeysEqViolated,
+    RequireNeqViolated,
+    RequireKeysNeqViolated,
+    RequireGtViolated,
+    RequireGteViolated,
+    AccountDiscriminatorAlreadySet,
+    AccountDiscriminatorNotFound,
+    AccountDiscriminatorMismatch,
+    AccountDidNotDeserialize,
+    AccountDidNotSerialize,
+    AccountNotEnoughKeys,
+    AccountNotMutable,
+    AccountOwnedByWrongProgram,
+    InvalidProgramId,
+    InvalidProgramExecutable,
+    AccountNotSigner,
+    AccountNotSystemOwned,
+    AccountNotInitialized,
+    AccountNotProgramData,
+    AccountNotAssociatedTokenAccount,
+    AccountSysvarMismatch,
+    AccountReallocExceedsLimit,
+    AccountDuplicateReallocs,
+    DeclaredProgramIdMismatch,
+    TryingToInitPayerAsProgramAccount,
+    InvalidNumericConversion,
+    /// Error defined by the lock program and named in its IDL.
+    Defined(ErrorCode),
+    /// Error defined by the lock program but missing from its IDL, as an index
+    /// into its error enum.
+    Program(u32),
+}
+
+// Anchor framework error codes, in the order Anchor assigns them.
+const ANCHOR_ERRORS: [(u32, LockProgramError); 55] = {
+    use LockProgramError::*;
+    [
+        (100, InstructionMissing),
+        (101, InstructionFallbackNotFound),
+        (102, InstructionDidNotDeserialize),
+        (103, InstructionDidNotSerialize),
+        (2000, ConstraintMut),
+        (2001, ConstraintHasOne),
+        (2002, ConstraintSigner),
+        (2003, ConstraintRaw),
+        (2004, ConstraintOwner),
+        (2005, ConstraintRentExempt),
+        (2006, ConstraintSeeds),
+        (2007, ConstraintExecutable),
+        (2009, ConstraintAssociated),
+        (2010, ConstraintAssociatedInit),
+        (2011, ConstraintClose),
+        (2012, ConstraintAddress),
+        (2013, ConstraintZero),
+        (2014, ConstraintTokenMint),
+        (2015, ConstraintTokenOwner),
+        (2016, ConstraintMintMintAuthority),
+        (2017, ConstraintMintFreezeAuthority),
+        (2018, ConstraintMintDecimals),
+        (2019, ConstraintSpace),
+        (2020, ConstraintAccountIsNone),
+        (2021, ConstraintTokenTokenProgram),
+        (2022, ConstraintMintTokenProgram),
+        (2023, ConstraintAssociatedTokenTokenProgram),
+        (2500, RequireViolated),
+        (2501, RequireEqViolated),
+        (2502, RequireKeysEqViolated),
+        (2503, RequireNeqViolated),
+        (2504, RequireKeysNeqViolated),
+        (2505, RequireGtViolated),
+        (2506, RequireGteViolated),
+        (3000, AccountDiscriminatorAlreadySet),
+        (3001, AccountDiscriminatorNotFound),
+        (3002, AccountDiscriminatorMismatch),
+        (3003, AccountDidNotDeserialize),
+        (3004, AccountDidNotSerialize),
+        (3005, AccountNotEnoughKeys),
+        (3006, AccountNotMutable),
+        (3007, AccountOwnedByWrongProgram),
+        (3008, InvalidProgramId),
+        (3009, InvalidProgramExecutable),
+        (3010, AccountNotSigner),
+        (3011, AccountNotSystemOwned),
+        (3012, AccountNotInitialized),
+        (3013, AccountNotProgramData),
+        (3014, AccountNotAssociatedTokenAccount),
+        (3015, AccountSysvarMismatch),
+        (3016, AccountReallocExceedsLimit),
+        (3017, AccountDuplicateReallocs),
+        (4100, DeclaredProgramIdMismatch),
+        (4101, TryingToInitPayerAsProgramAccount),
+        (4102, InvalidNumericConversion),
+    ]
+};
+
+impl LockProgramError {
+    /// Decodes a custom error code returned by the lock program.
+    pub fn from_code(code: u32) -> Option<Self> {
+        if code >= ANCHOR_ERROR_CODE_OFFSET {
+            return Some(
+                ErrorCode::ALL
+                    .iter()
+                    .find(|e| e.code() == code)
+                    .map_or(Self::Program(code - ANCHOR_ERROR_CODE_OFFSET), |e| {
+                        Self::Defined(*e)
+                    }),
+            );
+        }
+        ANCHOR_ERRORS
+            .iter()
+            .find(|(c, _)| *c == code)
+            .map(|(_, e)| *e)
+    }
+
+    /// Decodes a `ProgramError::Custom` returned by a CPI into the lock program.
+    pub fn from_program_error(error: &ProgramError) -> Option<Self> {
+        match error {
+            ProgramError::Custom(code) => Self::from_code(*code),
+            _ => None,
+        }
+    }
+
+    /// Custom error code as returned by the program.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::Defined(error) => error.code(),
+            Self::Program(index) => ANCHOR_ERROR_CODE_OFFSET + index,
+            _ => ANCHOR_ERRORS
+                .iter()
+                .find(|(_, e)| e == self)
+                .map(|(c, _)| *c)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Short message for logs, following Anchor's wording.
+    pub fn message(&self) -> &'static str {
+        use LockProgramError::*;
+        match self {
+            InstructionMissing => "8 byte instruction identifier not provided",
+            InstructionFallbackNotFound => "Fallback functions are not supported",
+            InstructionDidNotDeserialize => {
+                "The program could not deserialize the given instruction"
+            }
+            InstructionDidNotSerialize => "The program could not serialize the given instruction",
+            ConstraintMut => "A mut constraint was violated",
+            ConstraintHasOne => "A has one constraint was violated",
+            ConstraintSigner => "A signer constraint was violated",
+            ConstraintRaw => "A raw constraint was violated",
+            ConstraintOwner => "An owner constraint was violated",
+            ConstraintRentExempt => "A rent exemption constraint was violated",
+            ConstraintSeeds => "A seeds constraint was violated",
+            ConstraintExecutable => "An executable constraint was violated",
+            ConstraintAssociated => "An associated constraint was violated",
+            ConstraintAssociatedInit => "An associated init constraint was violated",
+            ConstraintClose => "A close constraint was violated",
+            ConstraintAddress => "An address constraint was violated",
+            ConstraintZero => "Expected zero account discriminant",
+            ConstraintTokenMint => "A token mint constraint was violated",
+            ConstraintTokenOwner => "A token owner constraint was violated",
+            ConstraintMintMintAuthority => "A mint mint authority constraint was violated",
+            ConstraintMintFreezeAuthority => "A mint freeze authority constraint was violated",
+            ConstraintMintDecimals => "A mint decimals constraint was violated",
+            ConstraintSpace => "A space constraint was violated",
+            ConstraintAccountIsNone => "A required account for the constraint is None",
+            ConstraintTokenTokenProgram => "A token account token program constraint was violated",
+            ConstraintMintTokenProgram => "A mint token program constraint was violated",
+            ConstraintAssociatedTokenTokenProgram => {
+                "An associated token account token program constraint was violated"
+            }
+            RequireViolated => "A require expression was violated",
+            RequireEqViolated => "A require_eq expression was violated",
+            RequireKeysEqViolated => "A require_keys_eq expression was violated",
+            RequireNeqViolated => "A require_neq expression was violated",
+            RequireKeysNeqViolated => "A require_keys_neq expression was violated",
+            RequireGtViolated => "A require_gt expression was violated",
+            RequireGteViolated => "A require_gte expression was violated",
+            AccountDiscriminatorAlreadySet => {
+                "The account discriminator was already set on this account"
+            }
+            AccountDiscriminatorNotFound => "No 8 byte discriminator was found on the account",
+            AccountDiscriminatorMismatch => "8 byte discriminator did not match what was expected",
+            AccountDidNotDeserialize => "Failed to deserialize the account",
+            AccountDidNotSerialize => "Failed to serialize the account",
+            AccountNotEnoughKeys => "Not enough account keys given to the instruction",
+            AccountNotMutable => "The given account is not mutable",
+            AccountOwnedByWrongProgram => {
+                "The given account is owned by a different program than expected"
+            }
+            InvalidProgramId => "Program ID was not as expected",
+            InvalidProgramExecutable => "Program account is not executable",
+            AccountNotSigner => "The given account did not sign",
+            AccountNotSystemOwned => "The given account is not owned by the system program",
+            AccountNotInitialized => "The program expected this account to be already initialized",
+            AccountNotProgramData => "The given account is not a program data account",
+            AccountNotAssociatedTokenAccount => {
+                "The given account is not the associated token account"
+            }
+            AccountSysvarMismatch => "The given public key does not match the required sysvar",
+            AccountReallocExceedsLimit => {
+                "The account reallocation exceeds the MAX_PERMITTED_DATA_INCREASE limit"
+            }
+            AccountDuplicateReallocs => "The account was duplicated for more than one reallocation",
+            DeclaredProgramIdMismatch => {
+                "The declared program id does not match the actual program id"
+            }
+            TryingToInitPayerAsProgramAccount => {
+                "You cannot/should not initialize the payer account as a program account"
+            }
+            InvalidNumericConversion => "Error during numeric conversion",
+            Defined(error) => error.message(),
+            Program(_) => "Lock program error",
+        }
+    }
+}
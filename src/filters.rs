@@ -0,0 +1,119 @@
+//! `getProgramAccounts` filters for the lock program's state accounts.
+//!
+//! Each query matches the account size and Anchor discriminator, plus a
+//! memcmp on one pubkey field. Offsets come from the generated state layouts
+//! (`LockedCpLiquidityState::POOL_ID_OFFSET`, ...), so they follow the IDL.
+//! Map the descriptors onto your RPC client, e.g.
+//! `RpcFilterType::Memcmp(Memcmp::new_base58_encoded(offset, &bytes))`.
+
+use std::{vec, vec::Vec};
+
+use pinocchio::pubkey::Pubkey;
+
+use crate::states::{LockedClmmPositionState, LockedCpLiquidityState};
+
+/// A `getProgramAccounts` filter, mirroring the RPC `dataSize` and `memcmp`
+/// filters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccountFilter {
+    DataSize(u64),
+    Memcmp { offset: usize, bytes: Vec<u8> },
+}
+
+impl AccountFilter {
+    /// Whether account `data` passes the filter, as an RPC node evaluates it.
+    pub fn matches(&self, data: &[u8]) -> bool {
+        match self {
+            Self::DataSize(size) => data.len() as u64 == *size,
+            Self::Memcmp { offset, bytes } => data
+                .get(*offset..)
+                .is_some_and(|data| data.starts_with(bytes)),
+        }
+    }
+}
+
+/// All `LockedCpLiquidityState` accounts.
+pub fn cp_locks() -> Vec<AccountFilter> {
+    account_filters(
+        LockedCpLiquidityState::LEN,
+        &LockedCpLiquidityState::DISCRIMINATOR,
+    )
+}
+
+/// CPMM locks of the pool `pool_id`.
+pub fn cp_locks_by_pool(pool_id: &Pubkey) -> Vec<AccountFilter> {
+    with_pubkey(cp_locks(), LockedCpLiquidityState::POOL_ID_OFFSET, pool_id)
+}
+
+/// CPMM locks created by `locked_owner`.
+pub fn cp_locks_by_owner(locked_owner: &Pubkey) -> Vec<AccountFilter> {
+    with_pubkey(
+        cp_locks(),
+        LockedCpLiquidityState::LOCKED_OWNER_OFFSET,
+        locked_owner,
+    )
+}
+
+/// The CPMM lock collected with `fee_nft_mint`. Its address is also
+/// `pda::find_locked_liquidity(fee_nft_mint)`.
+pub fn cp_lock_by_fee_nft_mint(fee_nft_mint: &Pubkey) -> Vec<AccountFilter> {
+    with_pubkey(
+        cp_locks(),
+        LockedCpLiquidityState::FEE_NFT_MINT_OFFSET,
+        fee_nft_mint,
+    )
+}
+
+/// All `LockedClmmPositionState` accounts.
+pub fn clmm_locks() -> Vec<AccountFilter> {
+    account_filters(
+        LockedClmmPositionState::LEN,
+        &LockedClmmPositionState::DISCRIMINATOR,
+    )
+}
+
+/// CLMM position locks of the pool `pool_id`.
+pub fn clmm_locks_by_pool(pool_id: &Pubkey) -> Vec<AccountFilter> {
+    with_pubkey(
+        clmm_locks(),
+        LockedClmmPositionState::POOL_ID_OFFSET,
+        pool_id,
+    )
+}
+
+/// CLMM position locks created by `position_owner`.
+pub fn clmm_locks_by_owner(position_owner: &Pubkey) -> Vec<AccountFilter> {
+    with_pubkey(
+        clmm_locks(),
+        LockedClmmPositionState::POSITION_OWNER_OFFSET,
+        position_owner,
+    )
+}
+
+/// The CLMM position lock collected with `fee_nft_mint`. Its address is also
+/// `pda::find_locked_position(fee_nft_mint)`.
+pub fn clmm_lock_by_fee_nft_mint(fee_nft_mint: &Pubkey) -> Vec<AccountFilter> {
+    with_pubkey(
+        clmm_locks(),
+        LockedClmmPositionState::FEE_NFT_MINT_OFFSET,
+        fee_nft_mint,
+    )
+}
+
+fn account_filters(len: usize, discriminator: &[u8; 8]) -> Vec<AccountFilter> {
+    vec![
+        AccountFilter::DataSize(len as u64),
+        AccountFilter::Memcmp {
+            offset: 0,
+            bytes: discriminator.to_vec(),
+        },
+    ]
+}
+
+fn with_pubkey(mut filters: Vec<AccountFilter>, offset: usize, key: &Pubkey) -> Vec<AccountFilter> {
+    filters.push(AccountFilter::Memcmp {
+        offset,
+        bytes: key.to_vec(),
+    });
+    filters
+}
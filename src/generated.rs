@@ -0,0 +1,893 @@
+//! Lock program bindings generated from `idl/raydium_liquidity_locking.json`.
+//!
+//! Do not edit by hand: run `cargo run -p pinocchio-raydium-locking-codegen`
+//! after updating the IDL. `tests/idl.rs` fails when this file is stale.
+
+use pinocchio::pubkey::Pubkey;
+
+use crate::{discriminator::anchor_discriminator, instructions::declare_cpi};
+
+declare_cpi! {
+    /// collect clmm locked postion fees and rewards
+    pub struct CollectClmmFeeAndReward {
+        discriminator: anchor_discriminator("global:collect_clmm_fees_and_rewards"),
+        accounts: [
+            authority: readonly,
+            fee_nft_owner: readonly_signer,
+            fee_nft_account: readonly,
+            locked_position: readonly,
+            clmm_program: readonly,
+            locked_nft_account: writable,
+            personal_position: writable,
+            pool_state: writable,
+            protocol_position: writable,
+            token_0_vault: writable,
+            token_1_vault: writable,
+            tick_array_lower: writable,
+            tick_array_upper: writable,
+            recipient_token_0_account: writable,
+            recipient_token_1_account: writable,
+            token_program: readonly,
+            token_program_2022: readonly,
+            memo_program: readonly,
+            vault_0_mint: readonly,
+            vault_1_mint: readonly,
+        ],
+        args: CollectClmmFeeAndRewardArgs {},
+        remaining_accounts: crate::instructions::MAX_REMAINING_ACCOUNTS,
+    }
+}
+
+declare_cpi! {
+    /// collect cpSwap locked liquidity's fees
+    pub struct CollectCpFee {
+        discriminator: anchor_discriminator("global:collect_cp_fees"),
+        accounts: [
+            authority: readonly,
+            fee_nft_owner: readonly_signer,
+            fee_nft_account: readonly,
+            locked_liquidity: writable,
+            cpmm_program: readonly,
+            cp_authority: readonly,
+            pool_state: writable,
+            lp_mint: writable,
+            recipient_token_0_account: writable,
+            recipient_token_1_account: writable,
+            token_0_vault: writable,
+            token_1_vault: writable,
+            vault_0_mint: readonly,
+            vault_1_mint: readonly,
+            locked_lp_vault: writable,
+            token_program: readonly,
+            token_program_2022: readonly,
+            memo_program: readonly,
+        ],
+        args: CollectCpFeeArgs {
+            fee_lp_amount: u64,
+        },
+    }
+}
+
+declare_cpi! {
+    /// Lock an existing clmm's position
+    pub struct LockClmmPosition {
+        discriminator: anchor_discriminator("global:lock_clmm_position"),
+        accounts: [
+            authority: readonly,
+            payer: writable_signer,
+            position_nft_owner: readonly_signer,
+            fee_nft_owner: readonly,
+            position_nft_account: writable,
+            personal_position: readonly,
+            position_nft_mint: readonly,
+            locked_nft_account: writable,
+            locked_position: writable,
+            fee_nft_mint: writable_signer,
+            fee_nft_account: writable,
+            metadata_account: writable,
+            metadata_program: readonly,
+            associated_token_program: readonly,
+            rent: readonly,
+            fee_nft_token_program: readonly,
+            locked_nft_token_program: readonly,
+            system_program: readonly,
+        ],
+        args: LockClmmPositionArgs {
+            with_metadata: bool,
+        },
+    }
+}
+
+declare_cpi! {
+    /// Lock cpSwap liquidity and mint a nft to collect locked liquidity's fee.
+    pub struct LockCpLiquidity {
+        discriminator: anchor_discriminator("global:lock_cp_liquidity"),
+        accounts: [
+            authority: readonly,
+            payer: writable_signer,
+            liquidity_owner: readonly_signer,
+            fee_nft_owner: readonly,
+            fee_nft_mint: writable_signer,
+            fee_nft_account: writable,
+            pool_state: readonly,
+            locked_liquidity: writable,
+            lp_mint: writable,
+            liquidity_owner_lp: writable,
+            locked_lp_vault: writable,
+            token_0_vault: writable,
+            token_1_vault: writable,
+            metadata_account: writable,
+            rent: readonly,
+            system_program: readonly,
+            token_program: readonly,
+            associated_token_program: readonly,
+            metadata_program: readonly,
+        ],
+        args: LockCpLiquidityArgs {
+            lp_amount: u64,
+            with_metadata: bool,
+        },
+    }
+}
+
+/// Off-chain builders for the instructions above, with one pubkey field per
+/// account; re-exported from `crate::client`.
+#[cfg(feature = "client")]
+pub(crate) mod client {
+    use crate::client::client_instruction;
+
+    client_instruction! {
+        /// collect clmm locked postion fees and rewards
+        pub struct CollectClmmFeeAndReward {
+            accounts: [
+                authority,
+                fee_nft_owner,
+                fee_nft_account,
+                locked_position,
+                clmm_program,
+                locked_nft_account,
+                personal_position,
+                pool_state,
+                protocol_position,
+                token_0_vault,
+                token_1_vault,
+                tick_array_lower,
+                tick_array_upper,
+                recipient_token_0_account,
+                recipient_token_1_account,
+                token_program,
+                token_program_2022,
+                memo_program,
+                vault_0_mint,
+                vault_1_mint,
+            ],
+            args: CollectClmmFeeAndRewardArgs {},
+            remaining_accounts,
+        }
+    }
+
+    client_instruction! {
+        /// collect cpSwap locked liquidity's fees
+        pub struct CollectCpFee {
+            accounts: [
+                authority,
+                fee_nft_owner,
+                fee_nft_account,
+                locked_liquidity,
+                cpmm_program,
+                cp_authority,
+                pool_state,
+                lp_mint,
+                recipient_token_0_account,
+                recipient_token_1_account,
+                token_0_vault,
+                token_1_vault,
+                vault_0_mint,
+                vault_1_mint,
+                locked_lp_vault,
+                token_program,
+                token_program_2022,
+                memo_program,
+            ],
+            args: CollectCpFeeArgs {
+                fee_lp_amount: u64,
+            },
+        }
+    }
+
+    client_instruction! {
+        /// Lock an existing clmm's position
+        pub struct LockClmmPosition {
+            accounts: [
+                authority,
+                payer,
+                position_nft_owner,
+                fee_nft_owner,
+                position_nft_account,
+                personal_position,
+                position_nft_mint,
+                locked_nft_account,
+                locked_position,
+                fee_nft_mint,
+                fee_nft_account,
+                metadata_account,
+                metadata_program,
+                associated_token_program,
+                rent,
+                fee_nft_token_program,
+                locked_nft_token_program,
+                system_program,
+            ],
+            args: LockClmmPositionArgs {
+                with_metadata: bool,
+            },
+        }
+    }
+
+    client_instruction! {
+        /// Lock cpSwap liquidity and mint a nft to collect locked liquidity's fee.
+        pub struct LockCpLiquidity {
+            accounts: [
+                authority,
+                payer,
+                liquidity_owner,
+                fee_nft_owner,
+                fee_nft_mint,
+                fee_nft_account,
+                pool_state,
+                locked_liquidity,
+                lp_mint,
+                liquidity_owner_lp,
+                locked_lp_vault,
+                token_0_vault,
+                token_1_vault,
+                metadata_account,
+                rent,
+                system_program,
+                token_program,
+                associated_token_program,
+                metadata_program,
+            ],
+            args: LockCpLiquidityArgs {
+                lp_amount: u64,
+                with_metadata: bool,
+            },
+        }
+    }
+}
+
+/// Typed views of a decoded instruction's accounts, with one accessor per
+/// account; re-exported from `crate::decode`.
+pub(crate) mod decode {
+    use crate::decode::decoded_accounts;
+
+    decoded_accounts! {
+        /// Accounts of a decoded `CollectClmmFeeAndReward`, by name.
+        pub struct CollectClmmFeeAndRewardAccounts for CollectClmmFeeAndReward {
+            authority: 0,
+            fee_nft_owner: 1,
+            fee_nft_account: 2,
+            locked_position: 3,
+            clmm_program: 4,
+            locked_nft_account: 5,
+            personal_position: 6,
+            pool_state: 7,
+            protocol_position: 8,
+            token_0_vault: 9,
+            token_1_vault: 10,
+            tick_array_lower: 11,
+            tick_array_upper: 12,
+            recipient_token_0_account: 13,
+            recipient_token_1_account: 14,
+            token_program: 15,
+            token_program_2022: 16,
+            memo_program: 17,
+            vault_0_mint: 18,
+            vault_1_mint: 19,
+        }
+    }
+
+    decoded_accounts! {
+        /// Accounts of a decoded `CollectCpFee`, by name.
+        pub struct CollectCpFeeAccounts for CollectCpFee {
+            authority: 0,
+            fee_nft_owner: 1,
+            fee_nft_account: 2,
+            locked_liquidity: 3,
+            cpmm_program: 4,
+            cp_authority: 5,
+            pool_state: 6,
+            lp_mint: 7,
+            recipient_token_0_account: 8,
+            recipient_token_1_account: 9,
+            token_0_vault: 10,
+            token_1_vault: 11,
+            vault_0_mint: 12,
+            vault_1_mint: 13,
+            locked_lp_vault: 14,
+            token_program: 15,
+            token_program_2022: 16,
+            memo_program: 17,
+        }
+    }
+
+    decoded_accounts! {
+        /// Accounts of a decoded `LockClmmPosition`, by name.
+        pub struct LockClmmPositionAccounts for LockClmmPosition {
+            authority: 0,
+            payer: 1,
+            position_nft_owner: 2,
+            fee_nft_owner: 3,
+            position_nft_account: 4,
+            personal_position: 5,
+            position_nft_mint: 6,
+            locked_nft_account: 7,
+            locked_position: 8,
+            fee_nft_mint: 9,
+            fee_nft_account: 10,
+            metadata_account: 11,
+            metadata_program: 12,
+            associated_token_program: 13,
+            rent: 14,
+            fee_nft_token_program: 15,
+            locked_nft_token_program: 16,
+            system_program: 17,
+        }
+    }
+
+    decoded_accounts! {
+        /// Accounts of a decoded `LockCpLiquidity`, by name.
+        pub struct LockCpLiquidityAccounts for LockCpLiquidity {
+            authority: 0,
+            payer: 1,
+            liquidity_owner: 2,
+            fee_nft_owner: 3,
+            fee_nft_mint: 4,
+            fee_nft_account: 5,
+            pool_state: 6,
+            locked_liquidity: 7,
+            lp_mint: 8,
+            liquidity_owner_lp: 9,
+            locked_lp_vault: 10,
+            token_0_vault: 11,
+            token_1_vault: 12,
+            metadata_account: 13,
+            rent: 14,
+            system_program: 15,
+            token_program: 16,
+            associated_token_program: 17,
+            metadata_program: 18,
+        }
+    }
+}
+
+/// Borsh layout of the lock program's `LockedClmmPositionState` account.
+///
+/// Every field is stored as a byte array so the struct has an alignment of 1
+/// and matches the on-chain data byte for byte; use the accessors to read it.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LockedClmmPositionState {
+    bump: [u8; 1],
+    position_owner: Pubkey,
+    pool_id: Pubkey,
+    position_id: Pubkey,
+    locked_nft_account: Pubkey,
+    fee_nft_mint: Pubkey,
+    recent_epoch: [u8; 8],
+    padding: [u8; 64],
+}
+
+const _: () = assert!(LockedClmmPositionState::LEN == 241);
+
+impl LockedClmmPositionState {
+    pub const LEN: usize = core::mem::size_of::<Self>() + 8;
+
+    pub const DISCRIMINATOR: [u8; 8] = anchor_discriminator("account:LockedClmmPositionState");
+
+    /// Offset of `bump` from the start of the account data.
+    pub const BUMP_OFFSET: usize = 8 + core::mem::offset_of!(Self, bump);
+
+    /// Offset of `position_owner` from the start of the account data.
+    pub const POSITION_OWNER_OFFSET: usize = 8 + core::mem::offset_of!(Self, position_owner);
+
+    /// Offset of `pool_id` from the start of the account data.
+    pub const POOL_ID_OFFSET: usize = 8 + core::mem::offset_of!(Self, pool_id);
+
+    /// Offset of `position_id` from the start of the account data.
+    pub const POSITION_ID_OFFSET: usize = 8 + core::mem::offset_of!(Self, position_id);
+
+    /// Offset of `locked_nft_account` from the start of the account data.
+    pub const LOCKED_NFT_ACCOUNT_OFFSET: usize =
+        8 + core::mem::offset_of!(Self, locked_nft_account);
+
+    /// Offset of `fee_nft_mint` from the start of the account data.
+    pub const FEE_NFT_MINT_OFFSET: usize = 8 + core::mem::offset_of!(Self, fee_nft_mint);
+
+    /// Offset of `recent_epoch` from the start of the account data.
+    pub const RECENT_EPOCH_OFFSET: usize = 8 + core::mem::offset_of!(Self, recent_epoch);
+
+    /// Bump to identify PDA
+    #[inline(always)]
+    pub fn bump(&self) -> u8 {
+        self.bump[0]
+    }
+
+    /// The owner who has locked clmm NFT
+    #[inline(always)]
+    pub fn position_owner(&self) -> &Pubkey {
+        &self.position_owner
+    }
+
+    /// The ID of the pool with which this record is connected
+    #[inline(always)]
+    pub fn pool_id(&self) -> &Pubkey {
+        &self.pool_id
+    }
+
+    /// The ID of the position with which this record is connected
+    #[inline(always)]
+    pub fn position_id(&self) -> &Pubkey {
+        &self.position_id
+    }
+
+    /// Program ATA locked NFT account or user ATA position NFT account
+    #[inline(always)]
+    pub fn locked_nft_account(&self) -> &Pubkey {
+        &self.locked_nft_account
+    }
+
+    /// nft mint to check who has authority to collect fee
+    #[inline(always)]
+    pub fn fee_nft_mint(&self) -> &Pubkey {
+        &self.fee_nft_mint
+    }
+
+    /// account update recent epoch
+    #[inline(always)]
+    pub fn recent_epoch(&self) -> u64 {
+        u64::from_le_bytes(self.recent_epoch)
+    }
+}
+
+#[cfg(feature = "test-utils")]
+impl LockedClmmPositionState {
+    /// # Safety
+    ///
+    /// Same requirements as `from_bytes`, and no other borrow of `bytes`
+    /// may be live.
+    #[inline(always)]
+    pub unsafe fn from_bytes_mut(bytes: &mut [u8]) -> &mut Self {
+        &mut *(bytes.as_mut_ptr() as *mut Self)
+    }
+
+    #[inline(always)]
+    pub fn set_bump(&mut self, value: u8) {
+        self.bump = [value];
+    }
+
+    #[inline(always)]
+    pub fn set_position_owner(&mut self, value: Pubkey) {
+        self.position_owner = value;
+    }
+
+    #[inline(always)]
+    pub fn set_pool_id(&mut self, value: Pubkey) {
+        self.pool_id = value;
+    }
+
+    #[inline(always)]
+    pub fn set_position_id(&mut self, value: Pubkey) {
+        self.position_id = value;
+    }
+
+    #[inline(always)]
+    pub fn set_locked_nft_account(&mut self, value: Pubkey) {
+        self.locked_nft_account = value;
+    }
+
+    #[inline(always)]
+    pub fn set_fee_nft_mint(&mut self, value: Pubkey) {
+        self.fee_nft_mint = value;
+    }
+
+    #[inline(always)]
+    pub fn set_recent_epoch(&mut self, value: u64) {
+        self.recent_epoch = value.to_le_bytes();
+    }
+
+    /// Starts an account fixture with every field zeroed.
+    pub fn builder() -> LockedClmmPositionStateBuilder {
+        LockedClmmPositionStateBuilder {
+            state: Self {
+                bump: [0; 1],
+                position_owner: [0; 32],
+                pool_id: [0; 32],
+                position_id: [0; 32],
+                locked_nft_account: [0; 32],
+                fee_nft_mint: [0; 32],
+                recent_epoch: [0; 8],
+                padding: [0; 64],
+            },
+        }
+    }
+}
+
+/// Builds `LockedClmmPositionState` account data for tests; unset fields stay zero.
+#[cfg(feature = "test-utils")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LockedClmmPositionStateBuilder {
+    state: LockedClmmPositionState,
+}
+
+#[cfg(feature = "test-utils")]
+impl LockedClmmPositionStateBuilder {
+    pub fn bump(mut self, value: u8) -> Self {
+        self.state.set_bump(value);
+        self
+    }
+
+    pub fn position_owner(mut self, value: Pubkey) -> Self {
+        self.state.set_position_owner(value);
+        self
+    }
+
+    pub fn pool_id(mut self, value: Pubkey) -> Self {
+        self.state.set_pool_id(value);
+        self
+    }
+
+    pub fn position_id(mut self, value: Pubkey) -> Self {
+        self.state.set_position_id(value);
+        self
+    }
+
+    pub fn locked_nft_account(mut self, value: Pubkey) -> Self {
+        self.state.set_locked_nft_account(value);
+        self
+    }
+
+    pub fn fee_nft_mint(mut self, value: Pubkey) -> Self {
+        self.state.set_fee_nft_mint(value);
+        self
+    }
+
+    pub fn recent_epoch(mut self, value: u64) -> Self {
+        self.state.set_recent_epoch(value);
+        self
+    }
+
+    /// The account fields as built.
+    pub fn build(&self) -> LockedClmmPositionState {
+        self.state
+    }
+
+    /// Complete account data: the Anchor discriminator followed by the
+    /// fields, `LEN` bytes in total.
+    pub fn to_account_bytes(&self) -> std::vec::Vec<u8> {
+        let mut data = std::vec::Vec::with_capacity(LockedClmmPositionState::LEN);
+        data.extend_from_slice(&LockedClmmPositionState::DISCRIMINATOR);
+        // SAFETY: the struct is plain bytes with an alignment of 1.
+        data.extend_from_slice(unsafe {
+            core::slice::from_raw_parts(
+                &self.state as *const LockedClmmPositionState as *const u8,
+                core::mem::size_of::<LockedClmmPositionState>(),
+            )
+        });
+        data
+    }
+
+    /// A writable, rent-exempt account at `key` owned by the lock program.
+    pub fn to_test_account(&self, key: Pubkey) -> crate::test_utils::TestAccount {
+        let lamports = crate::test_utils::rent_exempt_lamports(LockedClmmPositionState::LEN);
+        let data = self.to_account_bytes();
+        crate::test_utils::TestAccount::new(key, crate::ID, lamports, &data).writable()
+    }
+}
+
+/// Borsh layout of the lock program's `LockedCpLiquidityState` account.
+///
+/// Every field is stored as a byte array so the struct has an alignment of 1
+/// and matches the on-chain data byte for byte; use the accessors to read it.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LockedCpLiquidityState {
+    locked_lp_amount: [u8; 8],
+    claimed_lp_amount: [u8; 8],
+    unclaimed_lp_amount: [u8; 8],
+    last_lp: [u8; 8],
+    last_k: [u8; 16],
+    recent_epoch: [u8; 8],
+    pool_id: Pubkey,
+    fee_nft_mint: Pubkey,
+    locked_owner: Pubkey,
+    locked_lp_mint: Pubkey,
+    padding: [u8; 64],
+}
+
+const _: () = assert!(LockedCpLiquidityState::LEN == 256);
+
+impl LockedCpLiquidityState {
+    pub const LEN: usize = core::mem::size_of::<Self>() + 8;
+
+    pub const DISCRIMINATOR: [u8; 8] = anchor_discriminator("account:LockedCpLiquidityState");
+
+    /// Offset of `locked_lp_amount` from the start of the account data.
+    pub const LOCKED_LP_AMOUNT_OFFSET: usize = 8 + core::mem::offset_of!(Self, locked_lp_amount);
+
+    /// Offset of `claimed_lp_amount` from the start of the account data.
+    pub const CLAIMED_LP_AMOUNT_OFFSET: usize = 8 + core::mem::offset_of!(Self, claimed_lp_amount);
+
+    /// Offset of `unclaimed_lp_amount` from the start of the account data.
+    pub const UNCLAIMED_LP_AMOUNT_OFFSET: usize =
+        8 + core::mem::offset_of!(Self, unclaimed_lp_amount);
+
+    /// Offset of `last_lp` from the start of the account data.
+    pub const LAST_LP_OFFSET: usize = 8 + core::mem::offset_of!(Self, last_lp);
+
+    /// Offset of `last_k` from the start of the account data.
+    pub const LAST_K_OFFSET: usize = 8 + core::mem::offset_of!(Self, last_k);
+
+    /// Offset of `recent_epoch` from the start of the account data.
+    pub const RECENT_EPOCH_OFFSET: usize = 8 + core::mem::offset_of!(Self, recent_epoch);
+
+    /// Offset of `pool_id` from the start of the account data.
+    pub const POOL_ID_OFFSET: usize = 8 + core::mem::offset_of!(Self, pool_id);
+
+    /// Offset of `fee_nft_mint` from the start of the account data.
+    pub const FEE_NFT_MINT_OFFSET: usize = 8 + core::mem::offset_of!(Self, fee_nft_mint);
+
+    /// Offset of `locked_owner` from the start of the account data.
+    pub const LOCKED_OWNER_OFFSET: usize = 8 + core::mem::offset_of!(Self, locked_owner);
+
+    /// Offset of `locked_lp_mint` from the start of the account data.
+    pub const LOCKED_LP_MINT_OFFSET: usize = 8 + core::mem::offset_of!(Self, locked_lp_mint);
+
+    /// The Locked liquidity amount without claimed lp fee
+    #[inline(always)]
+    pub fn locked_lp_amount(&self) -> u64 {
+        u64::from_le_bytes(self.locked_lp_amount)
+    }
+
+    /// Claimed lp fee amount
+    #[inline(always)]
+    pub fn claimed_lp_amount(&self) -> u64 {
+        u64::from_le_bytes(self.claimed_lp_amount)
+    }
+
+    /// Unclaimed lp fee amount
+    #[inline(always)]
+    pub fn unclaimed_lp_amount(&self) -> u64 {
+        u64::from_le_bytes(self.unclaimed_lp_amount)
+    }
+
+    /// Last updated cp pool lp total supply
+    #[inline(always)]
+    pub fn last_lp(&self) -> u64 {
+        u64::from_le_bytes(self.last_lp)
+    }
+
+    /// Last updated cp pool k
+    #[inline(always)]
+    pub fn last_k(&self) -> u128 {
+        u128::from_le_bytes(self.last_k)
+    }
+
+    /// Account update recent epoch
+    #[inline(always)]
+    pub fn recent_epoch(&self) -> u64 {
+        u64::from_le_bytes(self.recent_epoch)
+    }
+
+    /// The ID of the pool with which this record is connected
+    #[inline(always)]
+    pub fn pool_id(&self) -> &Pubkey {
+        &self.pool_id
+    }
+
+    /// nft mint to check who has authority to collect fee
+    #[inline(always)]
+    pub fn fee_nft_mint(&self) -> &Pubkey {
+        &self.fee_nft_mint
+    }
+
+    /// The owner who has locked liquidity
+    #[inline(always)]
+    pub fn locked_owner(&self) -> &Pubkey {
+        &self.locked_owner
+    }
+
+    /// The mint of locked lp token
+    #[inline(always)]
+    pub fn locked_lp_mint(&self) -> &Pubkey {
+        &self.locked_lp_mint
+    }
+}
+
+#[cfg(feature = "test-utils")]
+impl LockedCpLiquidityState {
+    /// # Safety
+    ///
+    /// Same requirements as `from_bytes`, and no other borrow of `bytes`
+    /// may be live.
+    #[inline(always)]
+    pub unsafe fn from_bytes_mut(bytes: &mut [u8]) -> &mut Self {
+        &mut *(bytes.as_mut_ptr() as *mut Self)
+    }
+
+    #[inline(always)]
+    pub fn set_locked_lp_amount(&mut self, value: u64) {
+        self.locked_lp_amount = value.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn set_claimed_lp_amount(&mut self, value: u64) {
+        self.claimed_lp_amount = value.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn set_unclaimed_lp_amount(&mut self, value: u64) {
+        self.unclaimed_lp_amount = value.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn set_last_lp(&mut self, value: u64) {
+        self.last_lp = value.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn set_last_k(&mut self, value: u128) {
+        self.last_k = value.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn set_recent_epoch(&mut self, value: u64) {
+        self.recent_epoch = value.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn set_pool_id(&mut self, value: Pubkey) {
+        self.pool_id = value;
+    }
+
+    #[inline(always)]
+    pub fn set_fee_nft_mint(&mut self, value: Pubkey) {
+        self.fee_nft_mint = value;
+    }
+
+    #[inline(always)]
+    pub fn set_locked_owner(&mut self, value: Pubkey) {
+        self.locked_owner = value;
+    }
+
+    #[inline(always)]
+    pub fn set_locked_lp_mint(&mut self, value: Pubkey) {
+        self.locked_lp_mint = value;
+    }
+
+    /// Starts an account fixture with every field zeroed.
+    pub fn builder() -> LockedCpLiquidityStateBuilder {
+        LockedCpLiquidityStateBuilder {
+            state: Self {
+                locked_lp_amount: [0; 8],
+                claimed_lp_amount: [0; 8],
+                unclaimed_lp_amount: [0; 8],
+                last_lp: [0; 8],
+                last_k: [0; 16],
+                recent_epoch: [0; 8],
+                pool_id: [0; 32],
+                fee_nft_mint: [0; 32],
+                locked_owner: [0; 32],
+                locked_lp_mint: [0; 32],
+                padding: [0; 64],
+            },
+        }
+    }
+}
+
+/// Builds `LockedCpLiquidityState` account data for tests; unset fields stay zero.
+#[cfg(feature = "test-utils")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LockedCpLiquidityStateBuilder {
+    state: LockedCpLiquidityState,
+}
+
+#[cfg(feature = "test-utils")]
+impl LockedCpLiquidityStateBuilder {
+    pub fn locked_lp_amount(mut self, value: u64) -> Self {
+        self.state.set_locked_lp_amount(value);
+        self
+    }
+
+    pub fn claimed_lp_amount(mut self, value: u64) -> Self {
+        self.state.set_claimed_lp_amount(value);
+        self
+    }
+
+    pub fn unclaimed_lp_amount(mut self, value: u64) -> Self {
+        self.state.set_unclaimed_lp_amount(value);
+        self
+    }
+
+    pub fn last_lp(mut self, value: u64) -> Self {
+        self.state.set_last_lp(value);
+        self
+    }
+
+    pub fn last_k(mut self, value: u128) -> Self {
+        self.state.set_last_k(value);
+        self
+    }
+
+    pub fn recent_epoch(mut self, value: u64) -> Self {
+        self.state.set_recent_epoch(value);
+        self
+    }
+
+    pub fn pool_id(mut self, value: Pubkey) -> Self {
+        self.state.set_pool_id(value);
+        self
+    }
+
+    pub fn fee_nft_mint(mut self, value: Pubkey) -> Self {
+        self.state.set_fee_nft_mint(value);
+        self
+    }
+
+    pub fn locked_owner(mut self, value: Pubkey) -> Self {
+        self.state.set_locked_owner(value);
+        self
+    }
+
+    pub fn locked_lp_mint(mut self, value: Pubkey) -> Self {
+        self.state.set_locked_lp_mint(value);
+        self
+    }
+
+    /// The account fields as built.
+    pub fn build(&self) -> LockedCpLiquidityState {
+        self.state
+    }
+
+    /// Complete account data: the Anchor discriminator followed by the
+    /// fields, `LEN` bytes in total.
+    pub fn to_account_bytes(&self) -> std::vec::Vec<u8> {
+        let mut data = std::vec::Vec::with_capacity(LockedCpLiquidityState::LEN);
+        data.extend_from_slice(&LockedCpLiquidityState::DISCRIMINATOR);
+        // SAFETY: the struct is plain bytes with an alignment of 1.
+        data.extend_from_slice(unsafe {
+            core::slice::from_raw_parts(
+                &self.state as *const LockedCpLiquidityState as *const u8,
+                core::mem::size_of::<LockedCpLiquidityState>(),
+            )
+        });
+        data
+    }
+
+    /// A writable, rent-exempt account at `key` owned by the lock program.
+    pub fn to_test_account(&self, key: Pubkey) -> crate::test_utils::TestAccount {
+        let lamports = crate::test_utils::rent_exempt_lamports(LockedCpLiquidityState::LEN);
+        let data = self.to_account_bytes();
+        crate::test_utils::TestAccount::new(key, crate::ID, lamports, &data).writable()
+    }
+}
+
+/// Errors defined by the lock program's `#[error_code]` enum, with codes from
+/// `ANCHOR_ERROR_CODE_OFFSET` on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorCode {}
+
+impl ErrorCode {
+    /// Every error, in code order.
+    pub const ALL: [Self; 0] = [];
+
+    /// Custom error code as returned by the program.
+    pub const fn code(self) -> u32 {
+        match self {}
+    }
+
+    /// The error's `#[msg]`, or its name when it has none.
+    pub const fn message(self) -> &'static str {
+        match self {}
+    }
+}
@@ -1,92 +1,102 @@
-use core::slice::from_raw_parts;
-
 use pinocchio::{
-    account_info::AccountInfo,
-    instruction::{AccountMeta, Instruction, Signer},
-    program::invoke_signed,
+    instruction::{Seed, Signer},
+    pubkey::Pubkey,
     ProgramResult,
 };
 
-use crate::{write_bytes, UNINIT_BYTE};
+use crate::{
+    error::ValidationError,
+    instructions::{
+        invoke_with_signer, validate_pda, validate_program, validate_roles, validate_token_program,
+    },
+    pda,
+    programs::{
+        ASSOCIATED_TOKEN_PROGRAM_ID, METADATA_PROGRAM_ID, RENT_SYSVAR_ID, SYSTEM_PROGRAM_ID,
+    },
+};
 
-pub struct LockClmmPosition<'a> {
-    pub authority: &'a AccountInfo,                    // []          (readonly)
-    pub payer: &'a AccountInfo,                        // [SIGNER]    (signer + mutable)
-    pub position_nft_owner: &'a AccountInfo,           // [SIGNER]    (signer)
-    pub fee_nft_owner: &'a AccountInfo,                // []          (readonly)
-    pub position_nft_account: &'a AccountInfo,         // [WRITE]     (mutable)
-    pub personal_position: &'a AccountInfo,            // []          (readonly)
-    pub position_nft_mint: &'a AccountInfo,            // []          (readonly)
-    pub locked_nft_account: &'a AccountInfo,           // [WRITE]     (mutable)
-    pub locked_position: &'a AccountInfo,              // [WRITE]     (mutable)
-    pub fee_nft_mint: &'a AccountInfo,                 // [WRITE]     (mutable)
-    pub fee_nft_account: &'a AccountInfo,              // [WRITE]     (mutable)
-    pub metadata_account: &'a AccountInfo,             // [WRITE]     (mutable)
-    pub metadata_program: &'a AccountInfo,             // []          (readonly)
-    pub associated_token_program: &'a AccountInfo,     // []          (readonly)
-    pub rent: &'a AccountInfo,                         // []          (readonly)
-    pub fee_nft_token_program: &'a AccountInfo,        // []          (readonly)
-    pub locked_nft_token_program: &'a AccountInfo,     // []          (readonly)
-    pub system_program: &'a AccountInfo,               // []          (readonly)
-    
-    pub with_metadata: bool,
-}
+pub use crate::generated::{LockClmmPosition, LockClmmPositionArgs};
 
 impl LockClmmPosition<'_> {
+    /// Invokes the lock with `fee_nft_mint` signed by the calling program.
+    ///
+    /// `fee_nft_mint_seeds` are the calling program's seeds for the fee NFT
+    /// mint PDA, including the bump (e.g. `[b"fee_nft", pool, &[bump]]`).
     #[inline(always)]
-    pub fn invoke(&self) -> ProgramResult {
-        self.invoke_signed(&[])
+    pub fn invoke_with_fee_nft_mint(&self, fee_nft_mint_seeds: &[Seed]) -> ProgramResult {
+        self.invoke_signed(&[Signer::from(fee_nft_mint_seeds)])
     }
 
-    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
-        let account_metas: [AccountMeta; 18] = [
-            AccountMeta::readonly(self.authority.key()),
-            AccountMeta::writable_signer(self.payer.key()),
-            AccountMeta::readonly_signer(self.position_nft_owner.key()),
-            AccountMeta::readonly(self.fee_nft_owner.key()),
-            AccountMeta::writable(self.position_nft_account.key()),
-            AccountMeta::readonly(self.personal_position.key()),
-            AccountMeta::readonly(self.position_nft_mint.key()),
-            AccountMeta::writable(self.locked_nft_account.key()),
-            AccountMeta::writable(self.locked_position.key()),
-            AccountMeta::writable(self.fee_nft_mint.key()),
-            AccountMeta::writable(self.fee_nft_account.key()),
-            AccountMeta::writable(self.metadata_account.key()),
-            AccountMeta::readonly(self.metadata_program.key()),
-            AccountMeta::readonly(self.associated_token_program.key()),
-            AccountMeta::readonly(self.rent.key()),
-            AccountMeta::readonly(self.fee_nft_token_program.key()),
-            AccountMeta::readonly(self.locked_nft_token_program.key()),
-            AccountMeta::readonly(self.system_program.key()),
-        ];
+    /// Same as `invoke_with_fee_nft_mint`, with additional PDA signers
+    /// (e.g. a program-owned `position_nft_owner` or `payer`).
+    pub fn invoke_signed_with_fee_nft_mint(
+        &self,
+        fee_nft_mint_seeds: &[Seed],
+        signers: &[Signer],
+    ) -> ProgramResult {
+        invoke_with_signer(Signer::from(fee_nft_mint_seeds), signers, |signers| {
+            self.invoke_signed(signers)
+        })
+    }
 
-        // Instruction data layout:
-        // -  [0..8]: instruction discriminator (8 bytes)
-        // -  [8]: with_metadata (1 byte, bool)
-        let mut instruction_data = [UNINIT_BYTE; 9];
+    /// Checks account flags, program accounts and lock program PDAs before the CPI.
+    ///
+    /// A `fee_nft_mint` that did not sign the transaction fails with
+    /// `ValidationError::NotSigner("fee_nft_mint")`; for a fee NFT mint PDA,
+    /// use `validate_for_pda_signers` and `invoke_with_fee_nft_mint` instead.
+    ///
+    /// Derives several PDAs, so it costs noticeably more compute than `invoke`.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        self.validate_for_pda_signers(&[])
+    }
 
-        // Set discriminator (8 bytes) bc25b38352965449
-        let discriminator: [u8; 8] = [0xbc, 0x25, 0xb3, 0x83, 0x52, 0x96, 0x54, 0x49]; 
-        write_bytes(&mut instruction_data[0..8], &discriminator);
-        
-        // Set with_metadata (1 byte)
-        write_bytes(&mut instruction_data[8..], &[self.with_metadata as u8]);
+    /// Same as `validate`, skipping the signer flag of the accounts in
+    /// `pda_signers`, which the calling program signs for with seeds in
+    /// `invoke_signed`: a fee NFT mint PDA, or a program-owned `payer` or
+    /// `position_nft_owner`.
+    pub fn validate_for_pda_signers(&self, pda_signers: &[&Pubkey]) -> Result<(), ValidationError> {
+        validate_roles(&Self::ACCOUNTS, &self.account_infos(), pda_signers)?;
 
-        let instruction = Instruction {
-            program_id: &crate::ID,
-            accounts: &account_metas,
-            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, 9) },
-        };
+        validate_program(
+            "metadata_program",
+            self.metadata_program,
+            &METADATA_PROGRAM_ID,
+        )?;
+        validate_program(
+            "associated_token_program",
+            self.associated_token_program,
+            &ASSOCIATED_TOKEN_PROGRAM_ID,
+        )?;
+        validate_program("rent", self.rent, &RENT_SYSVAR_ID)?;
+        validate_token_program("fee_nft_token_program", self.fee_nft_token_program)?;
+        validate_token_program("locked_nft_token_program", self.locked_nft_token_program)?;
+        validate_program("system_program", self.system_program, &SYSTEM_PROGRAM_ID)?;
 
-        let accounts = [
-            self.authority, self.payer, self.position_nft_owner, self.fee_nft_owner,
-            self.position_nft_account, self.personal_position, self.position_nft_mint,
-            self.locked_nft_account, self.locked_position, self.fee_nft_mint,
-            self.fee_nft_account, self.metadata_account, self.metadata_program,
-            self.associated_token_program, self.rent, self.fee_nft_token_program,
-            self.locked_nft_token_program, self.system_program,
-        ];
+        let (authority, _) = pda::find_lock_clmm_authority();
+        validate_pda("authority", self.authority, &authority)?;
 
-        invoke_signed(&instruction, &accounts, signers)
+        let (locked_nft_account, _) = pda::find_associated_token_account(
+            &authority,
+            self.position_nft_mint.key(),
+            self.locked_nft_token_program.key(),
+        );
+        validate_pda(
+            "locked_nft_account",
+            self.locked_nft_account,
+            &locked_nft_account,
+        )?;
+
+        let (locked_position, _) = pda::find_locked_position(self.fee_nft_mint.key());
+        validate_pda("locked_position", self.locked_position, &locked_position)?;
+
+        let (fee_nft_account, _) = pda::find_fee_nft_account(
+            self.fee_nft_owner.key(),
+            self.fee_nft_mint.key(),
+            self.fee_nft_token_program.key(),
+        );
+        validate_pda("fee_nft_account", self.fee_nft_account, &fee_nft_account)?;
+
+        let (metadata_account, _) = pda::find_metadata_account(self.fee_nft_mint.key());
+        validate_pda("metadata_account", self.metadata_account, &metadata_account)
     }
-} 
\ No newline at end of file
+}
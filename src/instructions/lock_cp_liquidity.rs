@@ -1,99 +1,94 @@
-use core::slice::from_raw_parts;
-
 use pinocchio::{
-    account_info::AccountInfo,
-    instruction::{AccountMeta, Instruction, Signer},
-    program::invoke_signed,
+    instruction::{Seed, Signer},
+    pubkey::Pubkey,
     ProgramResult,
 };
 
-use crate::{write_bytes, UNINIT_BYTE};
-
-pub struct LockCpLiquidity<'a> {
-    pub authority: &'a AccountInfo,              // []          (readonly)
-    pub payer: &'a AccountInfo,                  // [SIGNER]    (signer + mutable)
-    pub liquidity_owner: &'a AccountInfo,        // [SIGNER]    (signer)
-    pub fee_nft_owner: &'a AccountInfo,          // []          (readonly)
-    pub fee_nft_mint: &'a AccountInfo,           // [WRITE]     (mutable)
-    pub fee_nft_account: &'a AccountInfo,        // [WRITE]     (mutable)
-    pub pool_state: &'a AccountInfo,             // []          (readonly)
-    pub locked_liquidity: &'a AccountInfo,       // [WRITE]     (mutable)
-    pub lp_mint: &'a AccountInfo,                // [WRITE]     (mutable)    
-    pub liquidity_owner_lp: &'a AccountInfo,     // [WRITE]     (mutable)
-    pub locked_lp_vault: &'a AccountInfo,        // [WRITE]     (mutable)
-    pub token_0_vault: &'a AccountInfo,          // [WRITE]     (mutable)
-    pub token_1_vault: &'a AccountInfo,          // [WRITE]     (mutable)   
-    pub metadata_account: &'a AccountInfo,       // [WRITE]     (mutable)
-    pub rent: &'a AccountInfo,                   // []          (readonly)   
-    pub system_program: &'a AccountInfo,         // []          (readonly)
-    pub token_program: &'a AccountInfo,          // []          (readonly)
-    pub associated_token_program: &'a AccountInfo,// []         (readonly)
-    pub metadata_program: &'a AccountInfo,       // []          (readonly)
-    
-    pub lp_amount: u64,
-    pub with_metadata: bool,
-}
+use crate::{
+    error::ValidationError,
+    instructions::{invoke_with_signer, validate_pda, validate_program, validate_roles},
+    pda,
+    programs::{
+        ASSOCIATED_TOKEN_PROGRAM_ID, METADATA_PROGRAM_ID, RENT_SYSVAR_ID, SYSTEM_PROGRAM_ID,
+        TOKEN_PROGRAM_ID,
+    },
+};
 
+pub use crate::generated::LockCpLiquidity;
+pub use crate::generated::LockCpLiquidityArgs;
 
 impl LockCpLiquidity<'_> {
+    /// Invokes the lock with `fee_nft_mint` signed by the calling program.
+    ///
+    /// `fee_nft_mint_seeds` are the calling program's seeds for the fee NFT
+    /// mint PDA, including the bump (e.g. `[b"fee_nft", pool, &[bump]]`).
     #[inline(always)]
-    pub fn invoke(&self) -> ProgramResult {
-        self.invoke_signed(&[])
+    pub fn invoke_with_fee_nft_mint(&self, fee_nft_mint_seeds: &[Seed]) -> ProgramResult {
+        self.invoke_signed(&[Signer::from(fee_nft_mint_seeds)])
     }
 
-    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
-        let account_metas: [AccountMeta; 19] = [
-            AccountMeta::readonly(self.authority.key()),
-            AccountMeta::writable_signer(self.payer.key()),
-            AccountMeta::readonly_signer(self.liquidity_owner.key()),
-            AccountMeta::readonly(self.fee_nft_owner.key()),
-            AccountMeta::writable(self.fee_nft_mint.key()),
-            AccountMeta::writable(self.fee_nft_account.key()),
-            AccountMeta::readonly(self.pool_state.key()),
-            AccountMeta::writable(self.locked_liquidity.key()),
-            AccountMeta::writable(self.lp_mint.key()),
-            AccountMeta::writable(self.liquidity_owner_lp.key()),
-            AccountMeta::writable(self.locked_lp_vault.key()),
-            AccountMeta::writable(self.token_0_vault.key()),
-            AccountMeta::writable(self.token_1_vault.key()),
-            AccountMeta::writable(self.metadata_account.key()),
-            AccountMeta::readonly(self.rent.key()),
-            AccountMeta::readonly(self.system_program.key()),
-            AccountMeta::readonly(self.token_program.key()),
-            AccountMeta::readonly(self.associated_token_program.key()),
-            AccountMeta::readonly(self.metadata_program.key()),
-        ];
-        // Instruction data layout:
-        // -  [0..8]: instruction discriminator (8 bytes)
-        // -  [8..16]: lp_amount (8 bytes, u64)
-        // -  [16]: with_metadata (1 byte, bool)
-        let mut instruction_data = [UNINIT_BYTE; 17];
+    /// Same as `invoke_with_fee_nft_mint`, with additional PDA signers
+    /// (e.g. a program-owned `liquidity_owner` or `payer`).
+    pub fn invoke_signed_with_fee_nft_mint(
+        &self,
+        fee_nft_mint_seeds: &[Seed],
+        signers: &[Signer],
+    ) -> ProgramResult {
+        invoke_with_signer(Signer::from(fee_nft_mint_seeds), signers, |signers| {
+            self.invoke_signed(signers)
+        })
+    }
 
-        // Set discriminator (8 bytes)
-        let discriminator: [u8; 8] = [0xd8, 0x9d, 0x1d, 0x4e, 0x26, 0x33, 0x1f, 0x1a];
-        write_bytes(&mut instruction_data[0..8], &discriminator);
-        
-        // Set lp_token_amount (8 bytes)
-        write_bytes(&mut instruction_data[8..16], &self.lp_amount.to_le_bytes());
+    /// Checks account flags, program accounts and lock program PDAs before the CPI.
+    ///
+    /// A `fee_nft_mint` that did not sign the transaction fails with
+    /// `ValidationError::NotSigner("fee_nft_mint")`; for a fee NFT mint PDA,
+    /// use `validate_for_pda_signers` and `invoke_with_fee_nft_mint` instead.
+    ///
+    /// Derives several PDAs, so it costs noticeably more compute than `invoke`.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        self.validate_for_pda_signers(&[])
+    }
 
-        // Set with_metadata (1 bytes)
-        write_bytes(&mut instruction_data[16..], &[self.with_metadata as u8]);
-        
-        let instruction = Instruction {
-            program_id: &crate::ID,
-            accounts: &account_metas,
-            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, 17) },
-        };
+    /// Same as `validate`, skipping the signer flag of the accounts in
+    /// `pda_signers`, which the calling program signs for with seeds in
+    /// `invoke_signed`: a fee NFT mint PDA, or a program-owned `payer` or
+    /// `liquidity_owner`.
+    pub fn validate_for_pda_signers(&self, pda_signers: &[&Pubkey]) -> Result<(), ValidationError> {
+        validate_roles(&Self::ACCOUNTS, &self.account_infos(), pda_signers)?;
 
-        let accounts = [
-            self.authority, self.payer, self.liquidity_owner, self.fee_nft_owner,
-            self.fee_nft_mint, self.fee_nft_account, self.pool_state, self.locked_liquidity,
-            self.lp_mint, self.liquidity_owner_lp, self.locked_lp_vault,
-            self.token_0_vault, self.token_1_vault, self.metadata_account,
-            self.rent, self.system_program, self.token_program,
-            self.associated_token_program, self.metadata_program,
-        ];
+        validate_program("rent", self.rent, &RENT_SYSVAR_ID)?;
+        validate_program("system_program", self.system_program, &SYSTEM_PROGRAM_ID)?;
+        validate_program("token_program", self.token_program, &TOKEN_PROGRAM_ID)?;
+        validate_program(
+            "associated_token_program",
+            self.associated_token_program,
+            &ASSOCIATED_TOKEN_PROGRAM_ID,
+        )?;
+        validate_program(
+            "metadata_program",
+            self.metadata_program,
+            &METADATA_PROGRAM_ID,
+        )?;
 
-        invoke_signed(&instruction, &accounts, signers)
+        let (authority, _) = pda::find_lock_cp_authority();
+        validate_pda("authority", self.authority, &authority)?;
+
+        let (locked_liquidity, _) = pda::find_locked_liquidity(self.fee_nft_mint.key());
+        validate_pda("locked_liquidity", self.locked_liquidity, &locked_liquidity)?;
+
+        let (locked_lp_vault, _) =
+            pda::find_associated_token_account(&authority, self.lp_mint.key(), &TOKEN_PROGRAM_ID);
+        validate_pda("locked_lp_vault", self.locked_lp_vault, &locked_lp_vault)?;
+
+        let (fee_nft_account, _) = pda::find_fee_nft_account(
+            self.fee_nft_owner.key(),
+            self.fee_nft_mint.key(),
+            &TOKEN_PROGRAM_ID,
+        );
+        validate_pda("fee_nft_account", self.fee_nft_account, &fee_nft_account)?;
+
+        let (metadata_account, _) = pda::find_metadata_account(self.fee_nft_mint.key());
+        validate_pda("metadata_account", self.metadata_account, &metadata_account)
     }
-} 
\ No newline at end of file
+}
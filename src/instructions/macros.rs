@@ -0,0 +1,320 @@
+/// Declares a lock program CPI instruction from its discriminator, ordered
+/// account list and typed arguments.
+///
+/// Generates the struct, `DISCRIMINATOR`, `DATA_LEN`, the `ACCOUNTS` role
+/// table, `from_accounts`, `invoke`/`invoke_signed` and the named arguments
+/// struct with its Borsh encoder and decoder, so the account order is only
+/// written once. Account roles are the `AccountRole` constructors: `readonly`,
+/// `writable`, `readonly_signer` and `writable_signer`. Arguments must
+/// implement `InstructionArg`.
+///
+/// Ending the spec with `remaining_accounts: MAX` adds a `remaining_accounts`
+/// field holding up to `MAX` extra accounts forwarded after the fixed ones.
+macro_rules! declare_cpi {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident {
+            discriminator: $discriminator:expr,
+            accounts: [$($account:ident: $role:ident),* $(,)?],
+            args: $args:ident { $($arg:ident: $arg_ty:ty),* $(,)? } $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        pub struct $name<'a> {
+            $(pub $account: &'a ::pinocchio::account_info::AccountInfo,)*
+            $(pub $arg: $arg_ty,)*
+        }
+
+        $crate::instructions::declare_cpi!(
+            @common $name, $discriminator, [$($account: $role),*], $args [$($arg: $arg_ty),*]
+        );
+
+        impl<'a> $name<'a> {
+            /// Builds the instruction from accounts in the lock program's order,
+            /// returning the accounts that follow them.
+            pub fn from_accounts(
+                accounts: &'a [::pinocchio::account_info::AccountInfo],
+                $($arg: $arg_ty,)*
+            ) -> Result<
+                (Self, &'a [::pinocchio::account_info::AccountInfo]),
+                ::pinocchio::program_error::ProgramError,
+            > {
+                let [$($account,)* remaining @ ..] = accounts else {
+                    return Err(::pinocchio::program_error::ProgramError::NotEnoughAccountKeys);
+                };
+
+                let instruction = Self { $($account,)* $($arg,)* };
+                Ok((instruction, remaining))
+            }
+
+            #[inline(always)]
+            pub fn invoke(&self) -> ::pinocchio::ProgramResult {
+                self.invoke_signed(&[])
+            }
+
+            pub fn invoke_signed(
+                &self,
+                signers: &[::pinocchio::instruction::Signer],
+            ) -> ::pinocchio::ProgramResult {
+                self.with_instruction(|instruction, accounts| {
+                    ::pinocchio::program::invoke_signed(instruction, accounts, signers)
+                })?
+            }
+
+            /// Builds the CPI instruction and its account list on the stack and
+            /// passes them to `f`, e.g. to inspect the metas or invoke it another way.
+            pub fn with_instruction<R>(
+                &self,
+                f: impl FnOnce(
+                    &::pinocchio::instruction::Instruction,
+                    &[&::pinocchio::account_info::AccountInfo; [$(stringify!($account)),*].len()],
+                ) -> R,
+            ) -> Result<R, ::pinocchio::program_error::ProgramError> {
+                const ACCOUNTS_LEN: usize = [$(stringify!($account)),*].len();
+
+                let accounts = self.account_infos();
+
+                let account_metas: [::pinocchio::instruction::AccountMeta; ACCOUNTS_LEN] =
+                    ::core::array::from_fn(|i| Self::ACCOUNTS[i].meta(accounts[i].key()));
+
+                let mut instruction_data = [$crate::UNINIT_BYTE; Self::DATA_LEN];
+                Self::write_data(&mut instruction_data, &self.args());
+
+                let instruction = ::pinocchio::instruction::Instruction {
+                    program_id: &$crate::ID,
+                    accounts: &account_metas,
+                    data: unsafe {
+                        ::core::slice::from_raw_parts(
+                            instruction_data.as_ptr() as _,
+                            Self::DATA_LEN,
+                        )
+                    },
+                };
+
+                Ok(f(&instruction, &accounts))
+            }
+        }
+    };
+
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident {
+            discriminator: $discriminator:expr,
+            accounts: [$($account:ident: $role:ident),* $(,)?],
+            args: $args:ident { $($arg:ident: $arg_ty:ty),* $(,)? },
+            remaining_accounts: $max_remaining:expr $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        pub struct $name<'a> {
+            $(pub $account: &'a ::pinocchio::account_info::AccountInfo,)*
+            $(pub $arg: $arg_ty,)*
+
+            /// Accounts forwarded after the fixed ones; signer/writable flags
+            /// are taken from each `AccountInfo`.
+            pub remaining_accounts: &'a [::pinocchio::account_info::AccountInfo],
+        }
+
+        $crate::instructions::declare_cpi!(
+            @common $name, $discriminator, [$($account: $role),*], $args [$($arg: $arg_ty),*]
+        );
+
+        impl<'a> $name<'a> {
+            /// Builds the instruction from accounts in the lock program's order,
+            /// taking the `remaining_accounts_len` accounts after the fixed ones
+            /// as `remaining_accounts` and returning the accounts that follow.
+            pub fn from_accounts(
+                accounts: &'a [::pinocchio::account_info::AccountInfo],
+                $($arg: $arg_ty,)*
+                remaining_accounts_len: usize,
+            ) -> Result<
+                (Self, &'a [::pinocchio::account_info::AccountInfo]),
+                ::pinocchio::program_error::ProgramError,
+            > {
+                let [$($account,)* rest @ ..] = accounts else {
+                    return Err(::pinocchio::program_error::ProgramError::NotEnoughAccountKeys);
+                };
+                if rest.len() < remaining_accounts_len {
+                    return Err(::pinocchio::program_error::ProgramError::NotEnoughAccountKeys);
+                }
+                let (remaining_accounts, rest) = rest.split_at(remaining_accounts_len);
+
+                let instruction = Self { $($account,)* $($arg,)* remaining_accounts };
+                Ok((instruction, rest))
+            }
+
+            #[inline(always)]
+            pub fn invoke(&self) -> ::pinocchio::ProgramResult {
+                self.invoke_signed(&[])
+            }
+
+            pub fn invoke_signed(
+                &self,
+                signers: &[::pinocchio::instruction::Signer],
+            ) -> ::pinocchio::ProgramResult {
+                self.with_instruction(|instruction, accounts| {
+                    ::pinocchio::cpi::slice_invoke_signed(instruction, accounts, signers)
+                })?
+            }
+
+            /// Builds the CPI instruction and its account list, fixed accounts
+            /// first, on the stack and passes them to `f`, e.g. to inspect the
+            /// metas or invoke it another way.
+            ///
+            /// Returns `InvalidArgument` if there are more than the maximum
+            /// number of `remaining_accounts`.
+            pub fn with_instruction<R>(
+                &self,
+                f: impl FnOnce(
+                    &::pinocchio::instruction::Instruction,
+                    &[&::pinocchio::account_info::AccountInfo],
+                ) -> R,
+            ) -> Result<R, ::pinocchio::program_error::ProgramError> {
+                use ::core::mem::MaybeUninit;
+                use ::pinocchio::{account_info::AccountInfo, instruction::AccountMeta};
+
+                const ACCOUNTS_LEN: usize = [$(stringify!($account)),*].len();
+                const MAX_ACCOUNTS: usize = ACCOUNTS_LEN + $max_remaining;
+
+                let remaining_len = self.remaining_accounts.len();
+                if remaining_len > $max_remaining {
+                    return Err(::pinocchio::program_error::ProgramError::InvalidArgument);
+                }
+
+                let mut account_metas =
+                    [const { MaybeUninit::<AccountMeta>::uninit() }; MAX_ACCOUNTS];
+                let mut accounts = [const { MaybeUninit::<&AccountInfo>::uninit() }; MAX_ACCOUNTS];
+
+                let fixed_accounts = self.account_infos();
+                for (i, (role, account)) in Self::ACCOUNTS.iter().zip(fixed_accounts).enumerate() {
+                    account_metas[i].write(role.meta(account.key()));
+                    accounts[i].write(account);
+                }
+
+                for (i, account) in self.remaining_accounts.iter().enumerate() {
+                    account_metas[ACCOUNTS_LEN + i].write(AccountMeta::new(
+                        account.key(),
+                        account.is_writable(),
+                        account.is_signer(),
+                    ));
+                    accounts[ACCOUNTS_LEN + i].write(account);
+                }
+
+                let len = ACCOUNTS_LEN + remaining_len;
+
+                let mut instruction_data = [$crate::UNINIT_BYTE; Self::DATA_LEN];
+                Self::write_data(&mut instruction_data, &self.args());
+
+                let instruction = ::pinocchio::instruction::Instruction {
+                    program_id: &$crate::ID,
+                    accounts: unsafe {
+                        ::core::slice::from_raw_parts(account_metas.as_ptr() as _, len)
+                    },
+                    data: unsafe {
+                        ::core::slice::from_raw_parts(
+                            instruction_data.as_ptr() as _,
+                            Self::DATA_LEN,
+                        )
+                    },
+                };
+
+                let accounts: &[&AccountInfo] =
+                    unsafe { ::core::slice::from_raw_parts(accounts.as_ptr() as _, len) };
+
+                Ok(f(&instruction, accounts))
+            }
+        }
+    };
+
+    (
+        @common $name:ident,
+        $discriminator:expr,
+        [$($account:ident: $role:ident),*],
+        $args:ident [$($arg:ident: $arg_ty:ty),*]
+    ) => {
+        #[doc = concat!("Arguments of `", stringify!($name), "`, in their Borsh order.")]
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+        pub struct $args {
+            $(pub $arg: $arg_ty,)*
+        }
+
+        impl $args {
+            /// Encoded length in bytes, without the discriminator.
+            pub const LEN: usize = 0 $(+ <$arg_ty as $crate::instructions::InstructionArg>::LEN)*;
+
+            #[inline(always)]
+            pub(crate) fn write<'b>(
+                &self,
+                out: &'b mut [::core::mem::MaybeUninit<u8>],
+            ) -> &'b mut [::core::mem::MaybeUninit<u8>] {
+                $(let out = $crate::instructions::InstructionArg::write(self.$arg, out);)*
+                out
+            }
+
+            /// Writes the Borsh encoding at the start of `out`, returning the
+            /// number of bytes written, or `None` if `out` is shorter than `LEN`.
+            pub fn encode_into(&self, out: &mut [u8]) -> Option<usize> {
+                if out.len() < Self::LEN {
+                    return None;
+                }
+                // SAFETY: `write` only stores initialized bytes.
+                let out = unsafe {
+                    &mut *(out as *mut [u8] as *mut [::core::mem::MaybeUninit<u8>])
+                };
+                self.write(out);
+                Some(Self::LEN)
+            }
+
+            /// Reads the arguments from the bytes following the discriminator.
+            ///
+            /// Like Anchor, bytes after the last argument are ignored.
+            pub fn decode(data: &[u8]) -> Result<Self, $crate::error::LockingError> {
+                let rest = data;
+                $(
+                    let ($arg, rest) =
+                        <$arg_ty as $crate::instructions::InstructionArg>::read(rest)
+                            .ok_or($crate::error::LockingError::InvalidInstructionData)?;
+                )*
+                let _ = rest;
+                Ok(Self { $($arg,)* })
+            }
+        }
+
+        impl $name<'_> {
+            pub const DISCRIMINATOR: [u8; 8] = $discriminator;
+
+            pub const DATA_LEN: usize = 8 + $args::LEN;
+
+            pub const ACCOUNTS: [
+                $crate::instructions::AccountRole;
+                [$(stringify!($account)),*].len()
+            ] = [$($crate::instructions::AccountRole::$role(stringify!($account))),*];
+
+            #[inline(always)]
+            pub(crate) fn account_infos(
+                &self,
+            ) -> [&::pinocchio::account_info::AccountInfo; [$(stringify!($account)),*].len()]
+            {
+                [$(self.$account),*]
+            }
+
+            /// Instruction arguments held by this struct.
+            #[inline(always)]
+            pub fn args(&self) -> $args {
+                $args { $($arg: self.$arg,)* }
+            }
+
+            /// Writes the discriminator followed by the Borsh-encoded arguments.
+            #[inline(always)]
+            pub(crate) fn write_data(
+                instruction_data: &mut [::core::mem::MaybeUninit<u8>],
+                args: &$args,
+            ) {
+                $crate::write_bytes(&mut instruction_data[..8], &Self::DISCRIMINATOR);
+                args.write(&mut instruction_data[8..]);
+            }
+        }
+    };
+}
+
+pub(crate) use declare_cpi;
@@ -1,10 +1,205 @@
+pub mod collect_clmm_fee_and_reward;
+pub mod collect_cp_fee_and_reward;
 pub mod lock_clmm_position;
 pub mod lock_cp_liquidity;
-pub mod collect_cp_fee_and_reward;
-pub mod collect_clmm_fee_and_reward;
-
+mod macros;
 
+pub use collect_clmm_fee_and_reward::*;
+pub use collect_cp_fee_and_reward::*;
 pub use lock_clmm_position::*;
 pub use lock_cp_liquidity::*;
-pub use collect_cp_fee_and_reward::*;
-pub use collect_clmm_fee_and_reward::*;
+pub(crate) use macros::declare_cpi;
+
+use core::{mem::MaybeUninit, slice::from_raw_parts};
+
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::{
+    error::ValidationError,
+    programs::{TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID},
+    write_bytes,
+};
+
+/// Name and signer/writable flags of one account in a lock program instruction.
+///
+/// Each instruction exposes its accounts as an `ACCOUNTS` table in the order the
+/// lock program expects them; the on-chain CPI and the off-chain client both
+/// build their account metas from it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AccountRole {
+    pub name: &'static str,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl AccountRole {
+    pub const fn readonly(name: &'static str) -> Self {
+        Self {
+            name,
+            is_signer: false,
+            is_writable: false,
+        }
+    }
+
+    pub const fn writable(name: &'static str) -> Self {
+        Self {
+            name,
+            is_signer: false,
+            is_writable: true,
+        }
+    }
+
+    pub const fn readonly_signer(name: &'static str) -> Self {
+        Self {
+            name,
+            is_signer: true,
+            is_writable: false,
+        }
+    }
+
+    pub const fn writable_signer(name: &'static str) -> Self {
+        Self {
+            name,
+            is_signer: true,
+            is_writable: true,
+        }
+    }
+
+    #[inline(always)]
+    pub fn meta<'a>(&self, pubkey: &'a Pubkey) -> AccountMeta<'a> {
+        AccountMeta::new(pubkey, self.is_writable, self.is_signer)
+    }
+}
+
+/// Borsh encoding of a lock program instruction argument.
+pub trait InstructionArg: Sized {
+    /// Encoded length in bytes.
+    const LEN: usize;
+
+    /// Writes the value at the start of `out` and returns the bytes after it.
+    fn write(self, out: &mut [MaybeUninit<u8>]) -> &mut [MaybeUninit<u8>];
+
+    /// Reads a value from the start of `data`, returning it with the bytes after it.
+    fn read(data: &[u8]) -> Option<(Self, &[u8])>;
+}
+
+impl InstructionArg for u64 {
+    const LEN: usize = 8;
+
+    #[inline(always)]
+    fn write(self, out: &mut [MaybeUninit<u8>]) -> &mut [MaybeUninit<u8>] {
+        let (value, rest) = out.split_at_mut(Self::LEN);
+        write_bytes(value, &self.to_le_bytes());
+        rest
+    }
+
+    #[inline(always)]
+    fn read(data: &[u8]) -> Option<(Self, &[u8])> {
+        let (value, rest) = data.split_first_chunk::<8>()?;
+        Some((u64::from_le_bytes(*value), rest))
+    }
+}
+
+impl InstructionArg for bool {
+    const LEN: usize = 1;
+
+    #[inline(always)]
+    fn write(self, out: &mut [MaybeUninit<u8>]) -> &mut [MaybeUninit<u8>] {
+        let (value, rest) = out.split_at_mut(Self::LEN);
+        write_bytes(value, &[self as u8]);
+        rest
+    }
+
+    // Borsh only accepts 0 and 1 for booleans
+    #[inline(always)]
+    fn read(data: &[u8]) -> Option<(Self, &[u8])> {
+        match data.split_first()? {
+            (0, rest) => Some((false, rest)),
+            (1, rest) => Some((true, rest)),
+            _ => None,
+        }
+    }
+}
+
+// Checks the signer/writable flags of `accounts` against their roles. Accounts
+// in `pda_signers` are signed for by the caller's seeds during the CPI, so
+// they are not transaction signers yet.
+pub(crate) fn validate_roles(
+    roles: &[AccountRole],
+    accounts: &[&AccountInfo],
+    pda_signers: &[&Pubkey],
+) -> Result<(), ValidationError> {
+    for (role, account) in roles.iter().zip(accounts) {
+        if role.is_signer && !account.is_signer() && !pda_signers.contains(&account.key()) {
+            return Err(ValidationError::NotSigner(role.name));
+        }
+        if role.is_writable && !account.is_writable() {
+            return Err(ValidationError::NotWritable(role.name));
+        }
+    }
+    Ok(())
+}
+
+#[inline(always)]
+pub(crate) fn validate_program(
+    name: &'static str,
+    account: &AccountInfo,
+    program_id: &Pubkey,
+) -> Result<(), ValidationError> {
+    if account.key() != program_id {
+        return Err(ValidationError::IncorrectProgramId(name));
+    }
+    Ok(())
+}
+
+#[inline(always)]
+pub(crate) fn validate_token_program(
+    name: &'static str,
+    account: &AccountInfo,
+) -> Result<(), ValidationError> {
+    if account.key() != &TOKEN_PROGRAM_ID && account.key() != &TOKEN_2022_PROGRAM_ID {
+        return Err(ValidationError::IncorrectProgramId(name));
+    }
+    Ok(())
+}
+
+#[inline(always)]
+pub(crate) fn validate_pda(
+    name: &'static str,
+    account: &AccountInfo,
+    expected: &Pubkey,
+) -> Result<(), ValidationError> {
+    if account.key() != expected {
+        return Err(ValidationError::InvalidPda(name));
+    }
+    Ok(())
+}
+
+// Maximum number of PDA signers a CPI accepts
+pub(crate) const MAX_SIGNERS: usize = 16;
+
+// Runs `invoke` with `signer` prepended to `signers`, without allocating.
+pub(crate) fn invoke_with_signer(
+    signer: Signer,
+    signers: &[Signer],
+    invoke: impl FnOnce(&[Signer]) -> ProgramResult,
+) -> ProgramResult {
+    if signers.len() >= MAX_SIGNERS {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let mut all_signers = [const { MaybeUninit::<Signer>::uninit() }; MAX_SIGNERS];
+    all_signers[0].write(signer);
+    for (slot, signer) in all_signers[1..].iter_mut().zip(signers) {
+        slot.write(signer.clone());
+    }
+
+    // SAFETY: the first `signers.len() + 1` entries were initialized above.
+    invoke(unsafe { from_raw_parts(all_signers.as_ptr() as *const Signer, signers.len() + 1) })
+}
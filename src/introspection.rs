@@ -0,0 +1,143 @@
+use core::ops::Deref;
+
+use pinocchio::{
+    account_info::{AccountInfo, Ref},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::instructions::{Instructions, IntrospectedInstruction},
+    ProgramResult,
+};
+
+use crate::{
+    decode::LockingInstruction,
+    error::LockingError,
+    instructions::{AccountRole, LockClmmPosition, LockCpLiquidity},
+    ID,
+};
+
+/// A lock found among the top-level instructions of the current transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LockedLiquidity {
+    Cp {
+        pool: Pubkey,
+        lp_mint: Pubkey,
+        owner: Pubkey,
+        lp_amount: u64,
+    },
+    Clmm {
+        personal_position: Pubkey,
+        position_nft_mint: Pubkey,
+        owner: Pubkey,
+    },
+}
+
+/// Iterates over the `LockCpLiquidity` and `LockClmmPosition` instructions
+/// sent to the lock program in the current transaction.
+///
+/// Only top-level instructions are visible through the Instructions sysvar,
+/// so locks performed via CPI from another program are not reported.
+pub struct LockedLiquidityIter<'a, T: Deref<Target = [u8]>> {
+    instructions: &'a Instructions<T>,
+    index: usize,
+}
+
+impl<'a, T: Deref<Target = [u8]>> LockedLiquidityIter<'a, T> {
+    pub fn new(instructions: &'a Instructions<T>) -> Self {
+        Self {
+            instructions,
+            index: 0,
+        }
+    }
+}
+
+impl<T: Deref<Target = [u8]>> Iterator for LockedLiquidityIter<'_, T> {
+    type Item = LockedLiquidity;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let instruction = self.instructions.load_instruction_at(self.index).ok()?;
+            self.index += 1;
+
+            if instruction.get_program_id() != &ID {
+                continue;
+            }
+
+            // Malformed lock instructions fail the transaction on their own
+            if let Ok(locked) = parse_lock(&instruction) {
+                return Some(locked);
+            }
+        }
+    }
+}
+
+/// Loads the Instructions sysvar from `instructions_sysvar`.
+pub fn load_instructions(
+    instructions_sysvar: &AccountInfo,
+) -> Result<Instructions<Ref<'_, [u8]>>, ProgramError> {
+    Instructions::try_from(instructions_sysvar)
+}
+
+/// Total LP of `pool` locked through `LockCpLiquidity` in the current transaction.
+pub fn cp_lp_locked<T: Deref<Target = [u8]>>(instructions: &Instructions<T>, pool: &Pubkey) -> u64 {
+    LockedLiquidityIter::new(instructions)
+        .filter_map(|locked| match locked {
+            LockedLiquidity::Cp {
+                pool: locked_pool,
+                lp_amount,
+                ..
+            } if &locked_pool == pool => Some(lp_amount),
+            _ => None,
+        })
+        .fold(0u64, u64::saturating_add)
+}
+
+/// Fails unless at least `min_lp_amount` LP of `pool` is locked by the current transaction.
+pub fn assert_cp_lp_locked(
+    instructions_sysvar: &AccountInfo,
+    pool: &Pubkey,
+    min_lp_amount: u64,
+) -> ProgramResult {
+    let instructions = load_instructions(instructions_sysvar)?;
+
+    if cp_lp_locked(&instructions, pool) < min_lp_amount {
+        return Err(LockingError::LiquidityNotLocked.into());
+    }
+
+    Ok(())
+}
+
+fn parse_lock(instruction: &IntrospectedInstruction) -> Result<LockedLiquidity, ProgramError> {
+    match LockingInstruction::unpack(instruction.get_instruction_data())? {
+        LockingInstruction::LockCpLiquidity { lp_amount, .. } => {
+            let roles = &LockCpLiquidity::ACCOUNTS;
+            Ok(LockedLiquidity::Cp {
+                pool: account_key(instruction, roles, "pool_state")?,
+                lp_mint: account_key(instruction, roles, "lp_mint")?,
+                owner: account_key(instruction, roles, "liquidity_owner")?,
+                lp_amount,
+            })
+        }
+        LockingInstruction::LockClmmPosition { .. } => {
+            let roles = &LockClmmPosition::ACCOUNTS;
+            Ok(LockedLiquidity::Clmm {
+                personal_position: account_key(instruction, roles, "personal_position")?,
+                position_nft_mint: account_key(instruction, roles, "position_nft_mint")?,
+                owner: account_key(instruction, roles, "position_nft_owner")?,
+            })
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+#[inline(always)]
+fn account_key(
+    instruction: &IntrospectedInstruction,
+    roles: &[AccountRole],
+    name: &str,
+) -> Result<Pubkey, ProgramError> {
+    let index = roles
+        .iter()
+        .position(|role| role.name == name)
+        .ok_or(ProgramError::InvalidArgument)?;
+    Ok(instruction.get_account_meta_at(index)?.key)
+}
@@ -1,7 +1,26 @@
 #![no_std]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "client")]
+pub mod client;
+pub mod decode;
+pub mod discriminator;
+pub mod error;
+#[cfg(feature = "std")]
+pub mod filters;
+mod generated;
 pub mod instructions;
+pub mod introspection;
+pub mod math;
+pub mod pda;
+pub mod programs;
+pub mod raydium;
 pub mod states;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+pub mod valuation;
 
 use core::mem::MaybeUninit;
 
@@ -0,0 +1,365 @@
+//! Fee previews for locked liquidity.
+//!
+//! All helpers use checked integer arithmetic and return `None` on overflow,
+//! division by zero or inputs the lock program would reject.
+
+use crate::{
+    raydium::clmm::{PersonalPositionState, PoolState, TickArrayState, TickState, REWARD_NUM},
+    states::LockedCpLiquidityState,
+};
+
+const Q64: u128 = 1 << 64;
+
+/// Current CPMM pool amounts used to value locked LP.
+///
+/// Vault amounts must exclude the pool's protocol, fund and creator fees,
+/// as the CPMM program does when pricing LP.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CpPoolAmounts {
+    pub token_0_vault_amount: u64,
+    pub token_1_vault_amount: u64,
+    pub lp_supply: u64,
+}
+
+impl CpPoolAmounts {
+    /// Pool invariant `k = token_0_vault_amount * token_1_vault_amount`.
+    #[inline]
+    pub fn k(&self) -> u128 {
+        self.token_0_vault_amount as u128 * self.token_1_vault_amount as u128
+    }
+}
+
+/// Fee LP a `CollectCpFee` can claim right now, and what burning it yields.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CpFeePreview {
+    pub fee_lp_amount: u64,
+    pub token_0_amount: u64,
+    pub token_1_amount: u64,
+}
+
+/// Fee LP that settling `locked_lp` would move out of the locked principal.
+///
+/// The value of one LP token is `sqrt(k) / lp_supply`. Fees grow it, so the
+/// locked principal keeps its value at settlement with fewer LP tokens:
+///
+/// `delta = locked_lp - ceil(locked_lp * sqrt(last_k) * curr_lp / (sqrt(curr_k) * last_lp))`
+///
+/// Returns `Some(0)` when the LP value did not grow.
+pub fn cp_fee_lp_delta(
+    locked_lp: u64,
+    last_lp: u64,
+    last_k: u128,
+    curr_lp: u64,
+    curr_k: u128,
+) -> Option<u64> {
+    if last_lp == 0 || curr_lp == 0 {
+        return None;
+    }
+
+    let numerator = (sqrt_u128(last_k) as u128).checked_mul(curr_lp as u128)?;
+    let denominator = (sqrt_u128(curr_k) as u128).checked_mul(last_lp as u128)?;
+    if denominator == 0 || numerator >= denominator {
+        return Some(0);
+    }
+
+    let remaining = mul_div_ceil(locked_lp as u128, numerator, denominator)?;
+    Some(locked_lp - remaining as u64)
+}
+
+/// Fee LP currently claimable from `state`: already settled fees plus the
+/// growth since the last settlement.
+pub fn cp_claimable_fee_lp(state: &LockedCpLiquidityState, pool: &CpPoolAmounts) -> Option<u64> {
+    let delta = cp_fee_lp_delta(
+        state.locked_lp_amount(),
+        state.last_lp(),
+        state.last_k(),
+        pool.lp_supply,
+        pool.k(),
+    )?;
+    state.unclaimed_lp_amount().checked_add(delta)
+}
+
+/// Token amounts received for burning `lp_amount`, rounded down.
+pub fn cp_lp_to_token_amounts(lp_amount: u64, pool: &CpPoolAmounts) -> Option<(u64, u64)> {
+    let lp_supply = pool.lp_supply as u128;
+    let token_0 = mul_div_floor(
+        lp_amount as u128,
+        pool.token_0_vault_amount as u128,
+        lp_supply,
+    )?;
+    let token_1 = mul_div_floor(
+        lp_amount as u128,
+        pool.token_1_vault_amount as u128,
+        lp_supply,
+    )?;
+    Some((u64::try_from(token_0).ok()?, u64::try_from(token_1).ok()?))
+}
+
+/// Claimable fee LP for `state` and the tokens burning it would yield.
+pub fn preview_cp_fee(
+    state: &LockedCpLiquidityState,
+    pool: &CpPoolAmounts,
+) -> Option<CpFeePreview> {
+    let fee_lp_amount = cp_claimable_fee_lp(state, pool)?;
+    let (token_0_amount, token_1_amount) = cp_lp_to_token_amounts(fee_lp_amount, pool)?;
+    Some(CpFeePreview {
+        fee_lp_amount,
+        token_0_amount,
+        token_1_amount,
+    })
+}
+
+/// Fees and rewards a `CollectClmmFeeAndReward` would collect right now.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ClmmFeePreview {
+    pub fee_amount_0: u64,
+    pub fee_amount_1: u64,
+    /// Indexed like `PoolState::reward_infos`; zero for uninitialized rewards.
+    pub reward_amounts: [u64; REWARD_NUM],
+}
+
+/// Fee growth per unit of liquidity inside `[tick_lower, tick_upper)`, for
+/// token 0 and token 1, as Q64.64.
+///
+/// The difference wraps like the CLMM program's, so only the change between
+/// two readings is meaningful.
+pub fn clmm_fee_growth_inside(
+    pool: &PoolState,
+    tick_lower: &TickState,
+    tick_upper: &TickState,
+) -> Option<(u128, u128)> {
+    let tick_current = pool.tick_current();
+    let inside_0 = growth_inside(
+        tick_current,
+        pool.fee_growth_global_0_x64(),
+        (tick_lower.tick(), tick_lower.fee_growth_outside_0_x64()),
+        (tick_upper.tick(), tick_upper.fee_growth_outside_0_x64()),
+    )?;
+    let inside_1 = growth_inside(
+        tick_current,
+        pool.fee_growth_global_1_x64(),
+        (tick_lower.tick(), tick_lower.fee_growth_outside_1_x64()),
+        (tick_upper.tick(), tick_upper.fee_growth_outside_1_x64()),
+    )?;
+    Some((inside_0, inside_1))
+}
+
+/// Each reward's global growth per unit of liquidity once the pool's rewards
+/// are brought up to `current_timestamp`, as the CLMM program does before
+/// collecting. Zero for uninitialized rewards.
+pub fn clmm_reward_growths_global(
+    pool: &PoolState,
+    current_timestamp: u64,
+) -> Option<[u128; REWARD_NUM]> {
+    let mut growths = [0; REWARD_NUM];
+    for (growth, reward_info) in growths.iter_mut().zip(pool.reward_infos()) {
+        if !reward_info.initialized() {
+            continue;
+        }
+        *growth = reward_info.reward_growth_global_x64();
+        if current_timestamp <= reward_info.open_time() || pool.liquidity() == 0 {
+            continue;
+        }
+
+        let latest_update_time = current_timestamp.min(reward_info.end_time());
+        let time_delta = latest_update_time.checked_sub(reward_info.last_update_time())?;
+        let growth_delta = mul_div_floor(
+            time_delta as u128,
+            reward_info.emissions_per_second_x64(),
+            pool.liquidity(),
+        )?;
+        *growth = growth.checked_add(growth_delta)?;
+    }
+    Some(growths)
+}
+
+/// Token 0, token 1 and reward amounts owed to `position` at
+/// `current_timestamp`, the on-chain clock's `unix_timestamp`.
+///
+/// `tick_array_lower` and `tick_array_upper` hold the position's lower and
+/// upper ticks, and may be the same array. Returns `None` if either tick is
+/// missing from them or the CLMM program would fail the collect.
+pub fn preview_clmm_fee(
+    pool: &PoolState,
+    position: &PersonalPositionState,
+    tick_array_lower: &TickArrayState,
+    tick_array_upper: &TickArrayState,
+    current_timestamp: u64,
+) -> Option<ClmmFeePreview> {
+    if tick_array_lower.pool_id() != position.pool_id()
+        || tick_array_upper.pool_id() != position.pool_id()
+    {
+        return None;
+    }
+    let tick_spacing = pool.tick_spacing();
+    let tick_lower = tick_array_lower.tick(position.tick_lower_index(), tick_spacing)?;
+    let tick_upper = tick_array_upper.tick(position.tick_upper_index(), tick_spacing)?;
+    let liquidity = position.liquidity();
+
+    let (inside_0, inside_1) = clmm_fee_growth_inside(pool, tick_lower, tick_upper)?;
+    let fee_amount_0 = position
+        .token_fees_owed_0()
+        .checked_add(underflow_u64(mul_div_floor(
+            inside_0.wrapping_sub(position.fee_growth_inside_0_last_x64()),
+            liquidity,
+            Q64,
+        )?))?;
+    let fee_amount_1 = position
+        .token_fees_owed_1()
+        .checked_add(underflow_u64(mul_div_floor(
+            inside_1.wrapping_sub(position.fee_growth_inside_1_last_x64()),
+            liquidity,
+            Q64,
+        )?))?;
+
+    let growths_global = clmm_reward_growths_global(pool, current_timestamp)?;
+    let growths_outside_lower = tick_lower.reward_growths_outside_x64();
+    let growths_outside_upper = tick_upper.reward_growths_outside_x64();
+    let mut reward_amounts = [0; REWARD_NUM];
+    for (i, reward_amount) in reward_amounts.iter_mut().enumerate() {
+        let reward_info = &position.reward_infos()[i];
+        let growth_inside = if pool.reward_infos()[i].initialized() {
+            growth_inside(
+                pool.tick_current(),
+                growths_global[i],
+                (tick_lower.tick(), growths_outside_lower[i]),
+                (tick_upper.tick(), growths_outside_upper[i]),
+            )?
+        } else {
+            0
+        };
+        // Rewards beyond a u128 are dropped rather than failing the collect
+        let delta = mul_div_floor(
+            growth_inside.wrapping_sub(reward_info.growth_inside_last_x64()),
+            liquidity,
+            Q64,
+        )
+        .map_or(0, underflow_u64);
+        *reward_amount = reward_info.reward_amount_owed().checked_add(delta)?;
+    }
+
+    Some(ClmmFeePreview {
+        fee_amount_0,
+        fee_amount_1,
+        reward_amounts,
+    })
+}
+
+// Growth inside a tick range from the global growth and the growth outside
+// each bound, given as `(tick, growth_outside)`.
+fn growth_inside(
+    tick_current: i32,
+    growth_global: u128,
+    (tick_lower, outside_lower): (i32, u128),
+    (tick_upper, outside_upper): (i32, u128),
+) -> Option<u128> {
+    let below = if tick_current >= tick_lower {
+        outside_lower
+    } else {
+        growth_global.checked_sub(outside_lower)?
+    };
+    let above = if tick_current < tick_upper {
+        outside_upper
+    } else {
+        growth_global.checked_sub(outside_upper)?
+    };
+    Some(growth_global.wrapping_sub(below).wrapping_sub(above))
+}
+
+// Amounts of `u64::MAX` or more become zero, as in the CLMM program: a
+// position that stays untouched long enough loses what it accrued.
+fn underflow_u64(amount: u128) -> u64 {
+    if amount < u64::MAX as u128 {
+        amount as u64
+    } else {
+        0
+    }
+}
+
+/// Integer square root, rounded down.
+pub fn sqrt_u128(value: u128) -> u64 {
+    if value < 2 {
+        return value as u64;
+    }
+
+    // Newton's method from an initial guess above the root
+    let shift = (128 - value.leading_zeros()).div_ceil(2);
+    let mut root = 1u128 << shift;
+    loop {
+        let next = (root + value / root) / 2;
+        if next >= root {
+            return root as u64;
+        }
+        root = next;
+    }
+}
+
+/// `a * b / denominator` rounded down, with a 256-bit intermediate product.
+pub fn mul_div_floor(a: u128, b: u128, denominator: u128) -> Option<u128> {
+    U256::mul(a, b)
+        .div_rem(denominator)
+        .map(|(quotient, _)| quotient)
+}
+
+/// `a * b / denominator` rounded up, with a 256-bit intermediate product.
+pub fn mul_div_ceil(a: u128, b: u128, denominator: u128) -> Option<u128> {
+    let (quotient, remainder) = U256::mul(a, b).div_rem(denominator)?;
+    if remainder == 0 {
+        Some(quotient)
+    } else {
+        quotient.checked_add(1)
+    }
+}
+
+// Unsigned 256-bit integer, only as wide as `mul_div_*` and the CLMM
+// liquidity math need.
+#[derive(Clone, Copy)]
+pub(crate) struct U256 {
+    pub(crate) hi: u128,
+    pub(crate) lo: u128,
+}
+
+impl U256 {
+    pub(crate) fn mul(a: u128, b: u128) -> Self {
+        const MASK: u128 = u64::MAX as u128;
+
+        let (a_hi, a_lo) = (a >> 64, a & MASK);
+        let (b_hi, b_lo) = (b >> 64, b & MASK);
+
+        let lo_lo = a_lo * b_lo;
+        let hi_lo = a_hi * b_lo;
+        let lo_hi = a_lo * b_hi;
+        let hi_hi = a_hi * b_hi;
+
+        let cross = (lo_lo >> 64) + (hi_lo & MASK) + (lo_hi & MASK);
+        let lo = (cross << 64) | (lo_lo & MASK);
+        let hi = hi_hi + (hi_lo >> 64) + (lo_hi >> 64) + (cross >> 64);
+
+        Self { hi, lo }
+    }
+
+    // Divides by `divisor`, returning `None` if the quotient does not fit in a u128.
+    pub(crate) fn div_rem(self, divisor: u128) -> Option<(u128, u128)> {
+        if divisor == 0 || self.hi >= divisor {
+            return None;
+        }
+        if self.hi == 0 {
+            return Some((self.lo / divisor, self.lo % divisor));
+        }
+
+        // Binary long division; `remainder < divisor` holds between steps
+        let mut remainder = self.hi;
+        let mut quotient = 0u128;
+        for bit in (0..128).rev() {
+            let carry = remainder >> 127;
+            remainder = (remainder << 1) | ((self.lo >> bit) & 1);
+            quotient <<= 1;
+            if carry == 1 || remainder >= divisor {
+                remainder = remainder.wrapping_sub(divisor);
+                quotient |= 1;
+            }
+        }
+
+        Some((quotient, remainder))
+    }
+}
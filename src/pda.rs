@@ -0,0 +1,416 @@
+use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
+use pinocchio_pubkey::pubkey;
+
+use crate::{
+    programs::{
+        ASSOCIATED_TOKEN_PROGRAM_ID, CLMM_PROGRAM_ID, CPMM_PROGRAM_ID, METADATA_PROGRAM_ID,
+    },
+    raydium::clmm::tick_array_start_index,
+    states::{LOCKED_LIQUIDITY_SEED, LOCKED_POSITION_SEED, LOCK_CLMM_AUTH_SEED, LOCK_CP_AUTH_SEED},
+    ID,
+};
+
+// Seed for Metaplex metadata accounts
+pub const METADATA_SEED: &str = "metadata";
+// Seed for the CPMM vault and LP mint authority
+pub const CPMM_AUTH_SEED: &str = "vault_and_lp_mint_auth_seed";
+// Seed for CLMM tick array accounts
+pub const TICK_ARRAY_SEED: &str = "tick_array";
+// Seed for CLMM personal and protocol position accounts
+pub const POSITION_SEED: &str = "position";
+
+// Lock CP authority and its bump, `[LOCK_CP_AUTH_SEED]` under the lock program
+pub const LOCK_CP_AUTHORITY: Pubkey = pubkey!("3f7GcQFG397GAaEnv51zR6tsTVihYRydnydDD1cXekxH");
+pub const LOCK_CP_AUTHORITY_BUMP: u8 = 255;
+// Lock CLMM authority and its bump, `[LOCK_CLMM_AUTH_SEED]` under the lock program
+pub const LOCK_CLMM_AUTHORITY: Pubkey = pubkey!("kN1kEznaF5Xbd8LYuqtEFcxzWSBk5Fv6ygX6SqEGJVy");
+pub const LOCK_CLMM_AUTHORITY_BUMP: u8 = 251;
+// CPMM vault and LP mint authority and its bump, `[CPMM_AUTH_SEED]` under the CPMM program
+pub const CPMM_AUTHORITY: Pubkey = pubkey!("GpMZbSM2GgvTKHJirzeGfMFoaZ8UR2X7F4v8vHTvxFbL");
+pub const CPMM_AUTHORITY_BUMP: u8 = 253;
+
+/// Finds a program derived address and its bump seed.
+///
+/// Uses the `sol_try_find_program_address` syscall on-chain and a local
+/// SHA-256 / curve check everywhere else, so the same seeds resolve to the
+/// same address in programs and in off-chain code. Returns `None` if there are
+/// too many seeds, a seed is too long or no bump yields a valid address.
+#[inline]
+pub fn find_program_address(seeds: &[&[u8]], program_id: &Pubkey) -> Option<(Pubkey, u8)> {
+    #[cfg(target_os = "solana")]
+    {
+        pinocchio::pubkey::try_find_program_address(seeds, program_id)
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    {
+        offchain::find_program_address(seeds, program_id)
+    }
+}
+
+// Finds the address for the crate's own seed layouts, which always fit the
+// seed limits; only a missing bump, with negligible odds, can fail.
+#[inline(always)]
+fn find(seeds: &[&[u8]], program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(seeds, program_id).expect("no viable bump seed")
+}
+
+/// Creates a program derived address from seeds that already include the bump.
+#[inline]
+pub fn create_program_address(
+    seeds: &[&[u8]],
+    program_id: &Pubkey,
+) -> Result<Pubkey, ProgramError> {
+    #[cfg(target_os = "solana")]
+    {
+        pinocchio::pubkey::checked_create_program_address(seeds, program_id)
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    {
+        offchain::create_program_address(seeds, program_id)
+    }
+}
+
+// Lock CP authority: [LOCK_CP_AUTH_SEED], precomputed as it takes no variable seeds
+#[inline(always)]
+pub const fn find_lock_cp_authority() -> (Pubkey, u8) {
+    (LOCK_CP_AUTHORITY, LOCK_CP_AUTHORITY_BUMP)
+}
+
+#[inline]
+pub fn create_lock_cp_authority_with_bump(bump: u8) -> Result<Pubkey, ProgramError> {
+    create_program_address(&[LOCK_CP_AUTH_SEED.as_bytes(), &[bump]], &ID)
+}
+
+// Lock CLMM authority: [LOCK_CLMM_AUTH_SEED], precomputed as it takes no variable seeds
+#[inline(always)]
+pub const fn find_lock_clmm_authority() -> (Pubkey, u8) {
+    (LOCK_CLMM_AUTHORITY, LOCK_CLMM_AUTHORITY_BUMP)
+}
+
+#[inline]
+pub fn create_lock_clmm_authority_with_bump(bump: u8) -> Result<Pubkey, ProgramError> {
+    create_program_address(&[LOCK_CLMM_AUTH_SEED.as_bytes(), &[bump]], &ID)
+}
+
+// LockedCpLiquidityState: [LOCKED_LIQUIDITY_SEED, fee_nft_mint]
+#[inline]
+pub fn find_locked_liquidity(fee_nft_mint: &Pubkey) -> (Pubkey, u8) {
+    find(&[LOCKED_LIQUIDITY_SEED.as_bytes(), fee_nft_mint], &ID)
+}
+
+#[inline]
+pub fn create_locked_liquidity_with_bump(
+    fee_nft_mint: &Pubkey,
+    bump: u8,
+) -> Result<Pubkey, ProgramError> {
+    create_program_address(
+        &[LOCKED_LIQUIDITY_SEED.as_bytes(), fee_nft_mint, &[bump]],
+        &ID,
+    )
+}
+
+// LockedClmmPositionState: [LOCKED_POSITION_SEED, fee_nft_mint]
+#[inline]
+pub fn find_locked_position(fee_nft_mint: &Pubkey) -> (Pubkey, u8) {
+    find(&[LOCKED_POSITION_SEED.as_bytes(), fee_nft_mint], &ID)
+}
+
+#[inline]
+pub fn create_locked_position_with_bump(
+    fee_nft_mint: &Pubkey,
+    bump: u8,
+) -> Result<Pubkey, ProgramError> {
+    create_program_address(
+        &[LOCKED_POSITION_SEED.as_bytes(), fee_nft_mint, &[bump]],
+        &ID,
+    )
+}
+
+// Associated token account: [wallet, token_program, mint] under the ATA program
+#[inline]
+pub fn find_associated_token_account(
+    wallet: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> (Pubkey, u8) {
+    find(&[wallet, token_program, mint], &ASSOCIATED_TOKEN_PROGRAM_ID)
+}
+
+#[inline]
+pub fn create_associated_token_account_with_bump(
+    wallet: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    bump: u8,
+) -> Result<Pubkey, ProgramError> {
+    create_program_address(
+        &[wallet, token_program, mint, &[bump]],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+}
+
+// Locked LP vault: lock CP authority's associated token account for the LP mint
+#[inline]
+pub fn find_locked_lp_vault(lp_mint: &Pubkey, token_program: &Pubkey) -> (Pubkey, u8) {
+    find_associated_token_account(&LOCK_CP_AUTHORITY, lp_mint, token_program)
+}
+
+#[inline]
+pub fn create_locked_lp_vault_with_bump(
+    lp_mint: &Pubkey,
+    token_program: &Pubkey,
+    bump: u8,
+) -> Result<Pubkey, ProgramError> {
+    create_associated_token_account_with_bump(&LOCK_CP_AUTHORITY, lp_mint, token_program, bump)
+}
+
+// Locked NFT account: lock CLMM authority's associated token account for the position NFT
+#[inline]
+pub fn find_locked_nft_account(position_nft_mint: &Pubkey, token_program: &Pubkey) -> (Pubkey, u8) {
+    find_associated_token_account(&LOCK_CLMM_AUTHORITY, position_nft_mint, token_program)
+}
+
+#[inline]
+pub fn create_locked_nft_account_with_bump(
+    position_nft_mint: &Pubkey,
+    token_program: &Pubkey,
+    bump: u8,
+) -> Result<Pubkey, ProgramError> {
+    create_associated_token_account_with_bump(
+        &LOCK_CLMM_AUTHORITY,
+        position_nft_mint,
+        token_program,
+        bump,
+    )
+}
+
+// Fee NFT account: fee NFT owner's associated token account for the fee NFT mint
+#[inline]
+pub fn find_fee_nft_account(
+    fee_nft_owner: &Pubkey,
+    fee_nft_mint: &Pubkey,
+    token_program: &Pubkey,
+) -> (Pubkey, u8) {
+    find_associated_token_account(fee_nft_owner, fee_nft_mint, token_program)
+}
+
+#[inline]
+pub fn create_fee_nft_account_with_bump(
+    fee_nft_owner: &Pubkey,
+    fee_nft_mint: &Pubkey,
+    token_program: &Pubkey,
+    bump: u8,
+) -> Result<Pubkey, ProgramError> {
+    create_associated_token_account_with_bump(fee_nft_owner, fee_nft_mint, token_program, bump)
+}
+
+// Metadata account: [METADATA_SEED, metadata_program, fee_nft_mint] under the metadata program
+#[inline]
+pub fn find_metadata_account(fee_nft_mint: &Pubkey) -> (Pubkey, u8) {
+    find(
+        &[METADATA_SEED.as_bytes(), &METADATA_PROGRAM_ID, fee_nft_mint],
+        &METADATA_PROGRAM_ID,
+    )
+}
+
+#[inline]
+pub fn create_metadata_account_with_bump(
+    fee_nft_mint: &Pubkey,
+    bump: u8,
+) -> Result<Pubkey, ProgramError> {
+    create_program_address(
+        &[
+            METADATA_SEED.as_bytes(),
+            &METADATA_PROGRAM_ID,
+            fee_nft_mint,
+            &[bump],
+        ],
+        &METADATA_PROGRAM_ID,
+    )
+}
+
+// CPMM authority (`cp_authority`): [CPMM_AUTH_SEED] under the CPMM program, precomputed
+#[inline(always)]
+pub const fn find_cpmm_authority() -> (Pubkey, u8) {
+    (CPMM_AUTHORITY, CPMM_AUTHORITY_BUMP)
+}
+
+#[inline]
+pub fn create_cpmm_authority_with_bump(bump: u8) -> Result<Pubkey, ProgramError> {
+    create_program_address(&[CPMM_AUTH_SEED.as_bytes(), &[bump]], &CPMM_PROGRAM_ID)
+}
+
+// CLMM tick array: [TICK_ARRAY_SEED, pool_state, start_tick_index (big endian)] under the CLMM program
+#[inline]
+pub fn find_tick_array(pool_state: &Pubkey, start_tick_index: i32) -> (Pubkey, u8) {
+    find(
+        &[
+            TICK_ARRAY_SEED.as_bytes(),
+            pool_state,
+            &start_tick_index.to_be_bytes(),
+        ],
+        &CLMM_PROGRAM_ID,
+    )
+}
+
+#[inline]
+pub fn create_tick_array_with_bump(
+    pool_state: &Pubkey,
+    start_tick_index: i32,
+    bump: u8,
+) -> Result<Pubkey, ProgramError> {
+    create_program_address(
+        &[
+            TICK_ARRAY_SEED.as_bytes(),
+            pool_state,
+            &start_tick_index.to_be_bytes(),
+            &[bump],
+        ],
+        &CLMM_PROGRAM_ID,
+    )
+}
+
+// CLMM protocol position: [POSITION_SEED, pool_state, tick_lower (BE), tick_upper (BE)] under the CLMM program
+#[inline]
+pub fn find_protocol_position(
+    pool_state: &Pubkey,
+    tick_lower_index: i32,
+    tick_upper_index: i32,
+) -> (Pubkey, u8) {
+    find(
+        &[
+            POSITION_SEED.as_bytes(),
+            pool_state,
+            &tick_lower_index.to_be_bytes(),
+            &tick_upper_index.to_be_bytes(),
+        ],
+        &CLMM_PROGRAM_ID,
+    )
+}
+
+#[inline]
+pub fn create_protocol_position_with_bump(
+    pool_state: &Pubkey,
+    tick_lower_index: i32,
+    tick_upper_index: i32,
+    bump: u8,
+) -> Result<Pubkey, ProgramError> {
+    create_program_address(
+        &[
+            POSITION_SEED.as_bytes(),
+            pool_state,
+            &tick_lower_index.to_be_bytes(),
+            &tick_upper_index.to_be_bytes(),
+            &[bump],
+        ],
+        &CLMM_PROGRAM_ID,
+    )
+}
+
+// CLMM personal position: [POSITION_SEED, position_nft_mint] under the CLMM program
+#[inline]
+pub fn find_personal_position(position_nft_mint: &Pubkey) -> (Pubkey, u8) {
+    find(
+        &[POSITION_SEED.as_bytes(), position_nft_mint],
+        &CLMM_PROGRAM_ID,
+    )
+}
+
+#[inline]
+pub fn create_personal_position_with_bump(
+    position_nft_mint: &Pubkey,
+    bump: u8,
+) -> Result<Pubkey, ProgramError> {
+    create_program_address(
+        &[POSITION_SEED.as_bytes(), position_nft_mint, &[bump]],
+        &CLMM_PROGRAM_ID,
+    )
+}
+
+/// CLMM accounts `CollectClmmFeeAndReward` needs for a position's tick range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClmmPositionPdas {
+    pub tick_array_lower: Pubkey,
+    pub tick_array_upper: Pubkey,
+    pub protocol_position: Pubkey,
+}
+
+/// Derives the tick arrays holding `tick_lower_index` and `tick_upper_index`
+/// and the protocol position of that range.
+///
+/// Returns `None` if `tick_spacing` is zero.
+pub fn find_clmm_position_pdas(
+    pool_state: &Pubkey,
+    tick_lower_index: i32,
+    tick_upper_index: i32,
+    tick_spacing: u16,
+) -> Option<ClmmPositionPdas> {
+    let lower_start = tick_array_start_index(tick_lower_index, tick_spacing)?;
+    let upper_start = tick_array_start_index(tick_upper_index, tick_spacing)?;
+
+    Some(ClmmPositionPdas {
+        tick_array_lower: find_tick_array(pool_state, lower_start).0,
+        tick_array_upper: find_tick_array(pool_state, upper_start).0,
+        protocol_position: find_protocol_position(pool_state, tick_lower_index, tick_upper_index).0,
+    })
+}
+
+#[cfg(not(target_os = "solana"))]
+mod offchain {
+    use curve25519_dalek::edwards::CompressedEdwardsY;
+    use pinocchio::{
+        program_error::ProgramError,
+        pubkey::{Pubkey, MAX_SEEDS, MAX_SEED_LEN},
+    };
+    use sha2::{Digest, Sha256};
+
+    const PDA_MARKER: &[u8; 21] = b"ProgramDerivedAddress";
+
+    pub fn create_program_address(
+        seeds: &[&[u8]],
+        program_id: &Pubkey,
+    ) -> Result<Pubkey, ProgramError> {
+        if seeds.len() > MAX_SEEDS || seeds.iter().any(|seed| seed.len() > MAX_SEED_LEN) {
+            return Err(ProgramError::MaxSeedLengthExceeded);
+        }
+
+        let mut hasher = Sha256::new();
+        for seed in seeds {
+            hasher.update(seed);
+        }
+        hasher.update(program_id);
+        hasher.update(PDA_MARKER);
+        let address: Pubkey = hasher.finalize().into();
+
+        // A valid program address must not be a point on the ed25519 curve
+        if CompressedEdwardsY(address).decompress().is_some() {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        Ok(address)
+    }
+
+    pub fn find_program_address(seeds: &[&[u8]], program_id: &Pubkey) -> Option<(Pubkey, u8)> {
+        // One seed slot is left for the bump
+        if seeds.len() >= MAX_SEEDS {
+            return None;
+        }
+
+        for bump in (0..=u8::MAX).rev() {
+            let bump_seed = [bump];
+            let mut seeds_with_bump: [&[u8]; MAX_SEEDS] = [&[]; MAX_SEEDS];
+            seeds_with_bump[..seeds.len()].copy_from_slice(seeds);
+            seeds_with_bump[seeds.len()] = &bump_seed;
+
+            match create_program_address(&seeds_with_bump[..=seeds.len()], program_id) {
+                Ok(address) => return Some((address, bump)),
+                Err(ProgramError::InvalidSeeds) => continue,
+                Err(_) => return None,
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,39 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+use pinocchio_pubkey::pubkey;
+
+use crate::error::LockingError;
+
+// SPL Token program
+pub const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+// SPL Token-2022 program
+pub const TOKEN_2022_PROGRAM_ID: Pubkey = pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+// SPL Associated Token Account program
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+// Metaplex Token Metadata program
+pub const METADATA_PROGRAM_ID: Pubkey = pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+// System program
+pub const SYSTEM_PROGRAM_ID: Pubkey = pubkey!("11111111111111111111111111111111");
+// SPL Memo program
+pub const MEMO_PROGRAM_ID: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+// Rent sysvar
+pub const RENT_SYSVAR_ID: Pubkey = pubkey!("SysvarRent111111111111111111111111111111111");
+// Raydium CPMM program
+pub const CPMM_PROGRAM_ID: Pubkey = pubkey!("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C");
+// Raydium CLMM program
+pub const CLMM_PROGRAM_ID: Pubkey = pubkey!("CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK");
+
+/// Returns the token program that owns `mint`, either SPL Token or Token-2022.
+///
+/// Use it to pick `token_program` for accounts derived from a mint, e.g. the
+/// associated token accounts of pool vault mints.
+#[inline]
+pub fn token_program_for_mint(mint: &AccountInfo) -> Result<&'static Pubkey, ProgramError> {
+    if mint.is_owned_by(&TOKEN_PROGRAM_ID) {
+        Ok(&TOKEN_PROGRAM_ID)
+    } else if mint.is_owned_by(&TOKEN_2022_PROGRAM_ID) {
+        Ok(&TOKEN_2022_PROGRAM_ID)
+    } else {
+        Err(LockingError::InvalidAccountOwner.into())
+    }
+}
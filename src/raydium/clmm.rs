@@ -0,0 +1,833 @@
+use pinocchio::pubkey::Pubkey;
+
+use crate::{
+    discriminator::anchor_discriminator,
+    pda::{find_clmm_position_pdas, ClmmPositionPdas},
+    programs::CLMM_PROGRAM_ID,
+    raydium::{impl_account_loaders, impl_field_offsets},
+};
+
+// Number of reward tokens per pool
+pub const REWARD_NUM: usize = 3;
+// Number of ticks per tick array
+pub const TICK_ARRAY_SIZE: usize = 60;
+
+/// Start tick index of the tick array containing `tick_index`.
+///
+/// Rounds towards negative infinity, so e.g. tick -1 with spacing 1 lives in
+/// the array starting at -60. Returns `None` if `tick_spacing` is zero.
+#[inline]
+pub fn tick_array_start_index(tick_index: i32, tick_spacing: u16) -> Option<i32> {
+    let ticks_in_array = TICK_ARRAY_SIZE as i32 * tick_spacing as i32;
+    if ticks_in_array == 0 {
+        return None;
+    }
+    Some(tick_index.div_euclid(ticks_in_array) * ticks_in_array)
+}
+
+/// Layout of the CLMM program's `PoolState` account.
+///
+/// Every field is stored as a byte array so the struct has an alignment of 1
+/// and matches the on-chain data byte for byte; use the accessors to read it.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PoolState {
+    bump: [u8; 1],
+    amm_config: Pubkey,
+    owner: Pubkey,
+    token_mint_0: Pubkey,
+    token_mint_1: Pubkey,
+    token_vault_0: Pubkey,
+    token_vault_1: Pubkey,
+    observation_key: Pubkey,
+    mint_decimals_0: u8,
+    mint_decimals_1: u8,
+    tick_spacing: [u8; 2],
+    liquidity: [u8; 16],
+    sqrt_price_x64: [u8; 16],
+    tick_current: [u8; 4],
+    padding3: [u8; 2],
+    padding4: [u8; 2],
+    fee_growth_global_0_x64: [u8; 16],
+    fee_growth_global_1_x64: [u8; 16],
+    protocol_fees_token_0: [u8; 8],
+    protocol_fees_token_1: [u8; 8],
+    padding5: [u8; 64],
+    status: u8,
+    fee_on: u8,
+    padding: [u8; 6],
+    reward_infos: [RewardInfo; REWARD_NUM],
+    tick_array_bitmap: [[u8; 8]; 16],
+    padding6: [u8; 32],
+    fund_fees_token_0: [u8; 8],
+    fund_fees_token_1: [u8; 8],
+    open_time: [u8; 8],
+    recent_epoch: [u8; 8],
+    dynamic_fee_info: DynamicFeeInfo,
+    padding1: [u8; 112],
+    padding2: [u8; 256],
+}
+
+const _: () = assert!(PoolState::LEN == 1544);
+
+impl PoolState {
+    pub const LEN: usize = core::mem::size_of::<Self>() + 8;
+
+    pub const DISCRIMINATOR: [u8; 8] = anchor_discriminator("account:PoolState");
+
+    impl_account_loaders!(CLMM_PROGRAM_ID);
+
+    impl_field_offsets!(account {
+        BUMP_OFFSET: bump,
+        AMM_CONFIG_OFFSET: amm_config,
+        OWNER_OFFSET: owner,
+        TOKEN_MINT_0_OFFSET: token_mint_0,
+        TOKEN_MINT_1_OFFSET: token_mint_1,
+        TOKEN_VAULT_0_OFFSET: token_vault_0,
+        TOKEN_VAULT_1_OFFSET: token_vault_1,
+        OBSERVATION_KEY_OFFSET: observation_key,
+        MINT_DECIMALS_0_OFFSET: mint_decimals_0,
+        MINT_DECIMALS_1_OFFSET: mint_decimals_1,
+        TICK_SPACING_OFFSET: tick_spacing,
+        LIQUIDITY_OFFSET: liquidity,
+        SQRT_PRICE_X64_OFFSET: sqrt_price_x64,
+        TICK_CURRENT_OFFSET: tick_current,
+        FEE_GROWTH_GLOBAL_0_X64_OFFSET: fee_growth_global_0_x64,
+        FEE_GROWTH_GLOBAL_1_X64_OFFSET: fee_growth_global_1_x64,
+        PROTOCOL_FEES_TOKEN_0_OFFSET: protocol_fees_token_0,
+        PROTOCOL_FEES_TOKEN_1_OFFSET: protocol_fees_token_1,
+        STATUS_OFFSET: status,
+        FEE_ON_OFFSET: fee_on,
+        REWARD_INFOS_OFFSET: reward_infos,
+        TICK_ARRAY_BITMAP_OFFSET: tick_array_bitmap,
+        FUND_FEES_TOKEN_0_OFFSET: fund_fees_token_0,
+        FUND_FEES_TOKEN_1_OFFSET: fund_fees_token_1,
+        OPEN_TIME_OFFSET: open_time,
+        RECENT_EPOCH_OFFSET: recent_epoch,
+        DYNAMIC_FEE_INFO_OFFSET: dynamic_fee_info,
+    });
+
+    #[inline(always)]
+    pub fn bump(&self) -> u8 {
+        self.bump[0]
+    }
+
+    #[inline(always)]
+    pub fn amm_config(&self) -> &Pubkey {
+        &self.amm_config
+    }
+
+    #[inline(always)]
+    pub fn owner(&self) -> &Pubkey {
+        &self.owner
+    }
+
+    #[inline(always)]
+    pub fn token_mint_0(&self) -> &Pubkey {
+        &self.token_mint_0
+    }
+
+    #[inline(always)]
+    pub fn token_mint_1(&self) -> &Pubkey {
+        &self.token_mint_1
+    }
+
+    #[inline(always)]
+    pub fn token_vault_0(&self) -> &Pubkey {
+        &self.token_vault_0
+    }
+
+    #[inline(always)]
+    pub fn token_vault_1(&self) -> &Pubkey {
+        &self.token_vault_1
+    }
+
+    #[inline(always)]
+    pub fn observation_key(&self) -> &Pubkey {
+        &self.observation_key
+    }
+
+    #[inline(always)]
+    pub fn mint_decimals_0(&self) -> u8 {
+        self.mint_decimals_0
+    }
+
+    #[inline(always)]
+    pub fn mint_decimals_1(&self) -> u8 {
+        self.mint_decimals_1
+    }
+
+    #[inline(always)]
+    pub fn tick_spacing(&self) -> u16 {
+        u16::from_le_bytes(self.tick_spacing)
+    }
+
+    /// Liquidity currently in range.
+    #[inline(always)]
+    pub fn liquidity(&self) -> u128 {
+        u128::from_le_bytes(self.liquidity)
+    }
+
+    /// Square root of the price of token 0 in token 1, as a Q64.64 number.
+    #[inline(always)]
+    pub fn sqrt_price_x64(&self) -> u128 {
+        u128::from_le_bytes(self.sqrt_price_x64)
+    }
+
+    #[inline(always)]
+    pub fn tick_current(&self) -> i32 {
+        i32::from_le_bytes(self.tick_current)
+    }
+
+    /// Token 0 fees earned per unit of liquidity over the pool's lifetime, as Q64.64.
+    #[inline(always)]
+    pub fn fee_growth_global_0_x64(&self) -> u128 {
+        u128::from_le_bytes(self.fee_growth_global_0_x64)
+    }
+
+    /// Token 1 fees earned per unit of liquidity over the pool's lifetime, as Q64.64.
+    #[inline(always)]
+    pub fn fee_growth_global_1_x64(&self) -> u128 {
+        u128::from_le_bytes(self.fee_growth_global_1_x64)
+    }
+
+    #[inline(always)]
+    pub fn protocol_fees_token_0(&self) -> u64 {
+        u64::from_le_bytes(self.protocol_fees_token_0)
+    }
+
+    #[inline(always)]
+    pub fn protocol_fees_token_1(&self) -> u64 {
+        u64::from_le_bytes(self.protocol_fees_token_1)
+    }
+
+    /// Bit flags disabling open position (bit 0), decrease liquidity (bit 1),
+    /// collect fee (bit 2), collect reward (bit 3) and swap (bit 4).
+    #[inline(always)]
+    pub fn status(&self) -> u8 {
+        self.status
+    }
+
+    #[inline(always)]
+    pub fn fee_on(&self) -> u8 {
+        self.fee_on
+    }
+
+    #[inline(always)]
+    pub fn reward_infos(&self) -> &[RewardInfo; REWARD_NUM] {
+        &self.reward_infos
+    }
+
+    /// Bitmap of initialized tick arrays around tick 0, as 16 little-endian words.
+    #[inline(always)]
+    pub fn tick_array_bitmap(&self) -> [u64; 16] {
+        core::array::from_fn(|i| u64::from_le_bytes(self.tick_array_bitmap[i]))
+    }
+
+    #[inline(always)]
+    pub fn fund_fees_token_0(&self) -> u64 {
+        u64::from_le_bytes(self.fund_fees_token_0)
+    }
+
+    #[inline(always)]
+    pub fn fund_fees_token_1(&self) -> u64 {
+        u64::from_le_bytes(self.fund_fees_token_1)
+    }
+
+    #[inline(always)]
+    pub fn open_time(&self) -> u64 {
+        u64::from_le_bytes(self.open_time)
+    }
+
+    #[inline(always)]
+    pub fn recent_epoch(&self) -> u64 {
+        u64::from_le_bytes(self.recent_epoch)
+    }
+
+    #[inline(always)]
+    pub fn dynamic_fee_info(&self) -> &DynamicFeeInfo {
+        &self.dynamic_fee_info
+    }
+}
+
+/// Reward emission state stored in the CLMM `PoolState`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RewardInfo {
+    reward_state: u8,
+    open_time: [u8; 8],
+    end_time: [u8; 8],
+    last_update_time: [u8; 8],
+    emissions_per_second_x64: [u8; 16],
+    reward_total_emitted: [u8; 8],
+    reward_claimed: [u8; 8],
+    token_mint: Pubkey,
+    token_vault: Pubkey,
+    authority: Pubkey,
+    reward_growth_global_x64: [u8; 16],
+}
+
+const _: () = assert!(core::mem::size_of::<RewardInfo>() == 169);
+
+impl RewardInfo {
+    impl_field_offsets!(nested {
+        REWARD_STATE_OFFSET: reward_state,
+        OPEN_TIME_OFFSET: open_time,
+        END_TIME_OFFSET: end_time,
+        LAST_UPDATE_TIME_OFFSET: last_update_time,
+        EMISSIONS_PER_SECOND_X64_OFFSET: emissions_per_second_x64,
+        REWARD_TOTAL_EMITTED_OFFSET: reward_total_emitted,
+        REWARD_CLAIMED_OFFSET: reward_claimed,
+        TOKEN_MINT_OFFSET: token_mint,
+        TOKEN_VAULT_OFFSET: token_vault,
+        AUTHORITY_OFFSET: authority,
+        REWARD_GROWTH_GLOBAL_X64_OFFSET: reward_growth_global_x64,
+    });
+
+    /// 0 when the reward is uninitialized, then initialized, opening and ended.
+    #[inline(always)]
+    pub fn reward_state(&self) -> u8 {
+        self.reward_state
+    }
+
+    /// Whether the reward slot holds a reward token.
+    #[inline(always)]
+    pub fn initialized(&self) -> bool {
+        self.reward_state != 0
+    }
+
+    #[inline(always)]
+    pub fn open_time(&self) -> u64 {
+        u64::from_le_bytes(self.open_time)
+    }
+
+    #[inline(always)]
+    pub fn end_time(&self) -> u64 {
+        u64::from_le_bytes(self.end_time)
+    }
+
+    #[inline(always)]
+    pub fn last_update_time(&self) -> u64 {
+        u64::from_le_bytes(self.last_update_time)
+    }
+
+    #[inline(always)]
+    pub fn emissions_per_second_x64(&self) -> u128 {
+        u128::from_le_bytes(self.emissions_per_second_x64)
+    }
+
+    #[inline(always)]
+    pub fn reward_total_emitted(&self) -> u64 {
+        u64::from_le_bytes(self.reward_total_emitted)
+    }
+
+    #[inline(always)]
+    pub fn reward_claimed(&self) -> u64 {
+        u64::from_le_bytes(self.reward_claimed)
+    }
+
+    #[inline(always)]
+    pub fn token_mint(&self) -> &Pubkey {
+        &self.token_mint
+    }
+
+    #[inline(always)]
+    pub fn token_vault(&self) -> &Pubkey {
+        &self.token_vault
+    }
+
+    #[inline(always)]
+    pub fn authority(&self) -> &Pubkey {
+        &self.authority
+    }
+
+    /// Rewards emitted per unit of liquidity over the reward's lifetime, as Q64.64.
+    #[inline(always)]
+    pub fn reward_growth_global_x64(&self) -> u128 {
+        u128::from_le_bytes(self.reward_growth_global_x64)
+    }
+}
+
+/// Dynamic fee parameters stored in the CLMM `PoolState`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DynamicFeeInfo {
+    filter_period: [u8; 2],
+    decay_period: [u8; 2],
+    reduction_factor: [u8; 2],
+    dynamic_fee_control: [u8; 4],
+    max_volatility_accumulator: [u8; 4],
+    tick_spacing_index_reference: [u8; 4],
+    volatility_reference: [u8; 4],
+    volatility_accumulator: [u8; 4],
+    last_update_timestamp: [u8; 8],
+    padding: [u8; 46],
+}
+
+const _: () = assert!(core::mem::size_of::<DynamicFeeInfo>() == 80);
+
+impl DynamicFeeInfo {
+    impl_field_offsets!(nested {
+        FILTER_PERIOD_OFFSET: filter_period,
+        DECAY_PERIOD_OFFSET: decay_period,
+        REDUCTION_FACTOR_OFFSET: reduction_factor,
+        DYNAMIC_FEE_CONTROL_OFFSET: dynamic_fee_control,
+        MAX_VOLATILITY_ACCUMULATOR_OFFSET: max_volatility_accumulator,
+        TICK_SPACING_INDEX_REFERENCE_OFFSET: tick_spacing_index_reference,
+        VOLATILITY_REFERENCE_OFFSET: volatility_reference,
+        VOLATILITY_ACCUMULATOR_OFFSET: volatility_accumulator,
+        LAST_UPDATE_TIMESTAMP_OFFSET: last_update_timestamp,
+    });
+
+    #[inline(always)]
+    pub fn filter_period(&self) -> u16 {
+        u16::from_le_bytes(self.filter_period)
+    }
+
+    #[inline(always)]
+    pub fn decay_period(&self) -> u16 {
+        u16::from_le_bytes(self.decay_period)
+    }
+
+    #[inline(always)]
+    pub fn reduction_factor(&self) -> u16 {
+        u16::from_le_bytes(self.reduction_factor)
+    }
+
+    #[inline(always)]
+    pub fn dynamic_fee_control(&self) -> u32 {
+        u32::from_le_bytes(self.dynamic_fee_control)
+    }
+
+    #[inline(always)]
+    pub fn max_volatility_accumulator(&self) -> u32 {
+        u32::from_le_bytes(self.max_volatility_accumulator)
+    }
+
+    #[inline(always)]
+    pub fn tick_spacing_index_reference(&self) -> i32 {
+        i32::from_le_bytes(self.tick_spacing_index_reference)
+    }
+
+    #[inline(always)]
+    pub fn volatility_reference(&self) -> u32 {
+        u32::from_le_bytes(self.volatility_reference)
+    }
+
+    #[inline(always)]
+    pub fn volatility_accumulator(&self) -> u32 {
+        u32::from_le_bytes(self.volatility_accumulator)
+    }
+
+    #[inline(always)]
+    pub fn last_update_timestamp(&self) -> u64 {
+        u64::from_le_bytes(self.last_update_timestamp)
+    }
+}
+
+/// Layout of the CLMM program's `PersonalPositionState` account, one per position NFT.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PersonalPositionState {
+    bump: [u8; 1],
+    nft_mint: Pubkey,
+    pool_id: Pubkey,
+    tick_lower_index: [u8; 4],
+    tick_upper_index: [u8; 4],
+    liquidity: [u8; 16],
+    fee_growth_inside_0_last_x64: [u8; 16],
+    fee_growth_inside_1_last_x64: [u8; 16],
+    token_fees_owed_0: [u8; 8],
+    token_fees_owed_1: [u8; 8],
+    reward_infos: [PositionRewardInfo; REWARD_NUM],
+    recent_epoch: [u8; 8],
+    padding: [u8; 56],
+}
+
+const _: () = assert!(PersonalPositionState::LEN == 281);
+
+impl PersonalPositionState {
+    pub const LEN: usize = core::mem::size_of::<Self>() + 8;
+
+    pub const DISCRIMINATOR: [u8; 8] = anchor_discriminator("account:PersonalPositionState");
+
+    impl_account_loaders!(CLMM_PROGRAM_ID);
+
+    impl_field_offsets!(account {
+        BUMP_OFFSET: bump,
+        NFT_MINT_OFFSET: nft_mint,
+        POOL_ID_OFFSET: pool_id,
+        TICK_LOWER_INDEX_OFFSET: tick_lower_index,
+        TICK_UPPER_INDEX_OFFSET: tick_upper_index,
+        LIQUIDITY_OFFSET: liquidity,
+        FEE_GROWTH_INSIDE_0_LAST_X64_OFFSET: fee_growth_inside_0_last_x64,
+        FEE_GROWTH_INSIDE_1_LAST_X64_OFFSET: fee_growth_inside_1_last_x64,
+        TOKEN_FEES_OWED_0_OFFSET: token_fees_owed_0,
+        TOKEN_FEES_OWED_1_OFFSET: token_fees_owed_1,
+        REWARD_INFOS_OFFSET: reward_infos,
+        RECENT_EPOCH_OFFSET: recent_epoch,
+    });
+
+    #[inline(always)]
+    pub fn bump(&self) -> u8 {
+        self.bump[0]
+    }
+
+    /// Mint of the NFT representing the position.
+    #[inline(always)]
+    pub fn nft_mint(&self) -> &Pubkey {
+        &self.nft_mint
+    }
+
+    #[inline(always)]
+    pub fn pool_id(&self) -> &Pubkey {
+        &self.pool_id
+    }
+
+    #[inline(always)]
+    pub fn tick_lower_index(&self) -> i32 {
+        i32::from_le_bytes(self.tick_lower_index)
+    }
+
+    #[inline(always)]
+    pub fn tick_upper_index(&self) -> i32 {
+        i32::from_le_bytes(self.tick_upper_index)
+    }
+
+    #[inline(always)]
+    pub fn liquidity(&self) -> u128 {
+        u128::from_le_bytes(self.liquidity)
+    }
+
+    /// Token 0 fee growth inside the position's range at its last update, as Q64.64.
+    #[inline(always)]
+    pub fn fee_growth_inside_0_last_x64(&self) -> u128 {
+        u128::from_le_bytes(self.fee_growth_inside_0_last_x64)
+    }
+
+    /// Token 1 fee growth inside the position's range at its last update, as Q64.64.
+    #[inline(always)]
+    pub fn fee_growth_inside_1_last_x64(&self) -> u128 {
+        u128::from_le_bytes(self.fee_growth_inside_1_last_x64)
+    }
+
+    /// Token 0 fees settled at the last update but not yet collected.
+    #[inline(always)]
+    pub fn token_fees_owed_0(&self) -> u64 {
+        u64::from_le_bytes(self.token_fees_owed_0)
+    }
+
+    /// Token 1 fees settled at the last update but not yet collected.
+    #[inline(always)]
+    pub fn token_fees_owed_1(&self) -> u64 {
+        u64::from_le_bytes(self.token_fees_owed_1)
+    }
+
+    /// Tick arrays and protocol position for this position's range, given
+    /// the pool's `tick_spacing`.
+    #[inline]
+    pub fn position_pdas(&self, tick_spacing: u16) -> Option<ClmmPositionPdas> {
+        find_clmm_position_pdas(
+            &self.pool_id,
+            self.tick_lower_index(),
+            self.tick_upper_index(),
+            tick_spacing,
+        )
+    }
+
+    #[inline(always)]
+    pub fn reward_infos(&self) -> &[PositionRewardInfo; REWARD_NUM] {
+        &self.reward_infos
+    }
+
+    #[inline(always)]
+    pub fn recent_epoch(&self) -> u64 {
+        u64::from_le_bytes(self.recent_epoch)
+    }
+}
+
+/// Per-reward state stored in a `PersonalPositionState`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PositionRewardInfo {
+    growth_inside_last_x64: [u8; 16],
+    reward_amount_owed: [u8; 8],
+}
+
+const _: () = assert!(core::mem::size_of::<PositionRewardInfo>() == 24);
+
+impl PositionRewardInfo {
+    impl_field_offsets!(nested {
+        GROWTH_INSIDE_LAST_X64_OFFSET: growth_inside_last_x64,
+        REWARD_AMOUNT_OWED_OFFSET: reward_amount_owed,
+    });
+
+    /// Reward growth inside the position's range at its last update, as Q64.64.
+    #[inline(always)]
+    pub fn growth_inside_last_x64(&self) -> u128 {
+        u128::from_le_bytes(self.growth_inside_last_x64)
+    }
+
+    /// Rewards settled at the last update but not yet collected.
+    #[inline(always)]
+    pub fn reward_amount_owed(&self) -> u64 {
+        u64::from_le_bytes(self.reward_amount_owed)
+    }
+}
+
+/// Layout of the CLMM program's `ProtocolPositionState` account, one per tick range.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ProtocolPositionState {
+    bump: u8,
+    pool_id: Pubkey,
+    tick_lower_index: [u8; 4],
+    tick_upper_index: [u8; 4],
+    liquidity: [u8; 16],
+    fee_growth_inside_0_last_x64: [u8; 16],
+    fee_growth_inside_1_last_x64: [u8; 16],
+    token_fees_owed_0: [u8; 8],
+    token_fees_owed_1: [u8; 8],
+    reward_growth_inside: [[u8; 16]; REWARD_NUM],
+    recent_epoch: [u8; 8],
+    padding: [u8; 56],
+}
+
+const _: () = assert!(ProtocolPositionState::LEN == 225);
+
+impl ProtocolPositionState {
+    pub const LEN: usize = core::mem::size_of::<Self>() + 8;
+
+    pub const DISCRIMINATOR: [u8; 8] = anchor_discriminator("account:ProtocolPositionState");
+
+    impl_account_loaders!(CLMM_PROGRAM_ID);
+
+    impl_field_offsets!(account {
+        BUMP_OFFSET: bump,
+        POOL_ID_OFFSET: pool_id,
+        TICK_LOWER_INDEX_OFFSET: tick_lower_index,
+        TICK_UPPER_INDEX_OFFSET: tick_upper_index,
+        LIQUIDITY_OFFSET: liquidity,
+        FEE_GROWTH_INSIDE_0_LAST_X64_OFFSET: fee_growth_inside_0_last_x64,
+        FEE_GROWTH_INSIDE_1_LAST_X64_OFFSET: fee_growth_inside_1_last_x64,
+        TOKEN_FEES_OWED_0_OFFSET: token_fees_owed_0,
+        TOKEN_FEES_OWED_1_OFFSET: token_fees_owed_1,
+        REWARD_GROWTH_INSIDE_OFFSET: reward_growth_inside,
+        RECENT_EPOCH_OFFSET: recent_epoch,
+    });
+
+    #[inline(always)]
+    pub fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    #[inline(always)]
+    pub fn pool_id(&self) -> &Pubkey {
+        &self.pool_id
+    }
+
+    #[inline(always)]
+    pub fn tick_lower_index(&self) -> i32 {
+        i32::from_le_bytes(self.tick_lower_index)
+    }
+
+    #[inline(always)]
+    pub fn tick_upper_index(&self) -> i32 {
+        i32::from_le_bytes(self.tick_upper_index)
+    }
+
+    #[inline(always)]
+    pub fn liquidity(&self) -> u128 {
+        u128::from_le_bytes(self.liquidity)
+    }
+
+    #[inline(always)]
+    pub fn fee_growth_inside_0_last_x64(&self) -> u128 {
+        u128::from_le_bytes(self.fee_growth_inside_0_last_x64)
+    }
+
+    #[inline(always)]
+    pub fn fee_growth_inside_1_last_x64(&self) -> u128 {
+        u128::from_le_bytes(self.fee_growth_inside_1_last_x64)
+    }
+
+    #[inline(always)]
+    pub fn token_fees_owed_0(&self) -> u64 {
+        u64::from_le_bytes(self.token_fees_owed_0)
+    }
+
+    #[inline(always)]
+    pub fn token_fees_owed_1(&self) -> u64 {
+        u64::from_le_bytes(self.token_fees_owed_1)
+    }
+
+    #[inline(always)]
+    pub fn reward_growth_inside(&self) -> [u128; REWARD_NUM] {
+        core::array::from_fn(|i| u128::from_le_bytes(self.reward_growth_inside[i]))
+    }
+
+    #[inline(always)]
+    pub fn recent_epoch(&self) -> u64 {
+        u64::from_le_bytes(self.recent_epoch)
+    }
+}
+
+/// Layout of the CLMM program's `TickArrayState` account, holding
+/// `TICK_ARRAY_SIZE` consecutive initializable ticks.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TickArrayState {
+    pool_id: Pubkey,
+    start_tick_index: [u8; 4],
+    ticks: [TickState; TICK_ARRAY_SIZE],
+    initialized_tick_count: u8,
+    recent_epoch: [u8; 8],
+    padding: [u8; 107],
+}
+
+const _: () = assert!(TickArrayState::LEN == 10240);
+
+impl TickArrayState {
+    pub const LEN: usize = core::mem::size_of::<Self>() + 8;
+
+    pub const DISCRIMINATOR: [u8; 8] = anchor_discriminator("account:TickArrayState");
+
+    impl_account_loaders!(CLMM_PROGRAM_ID);
+
+    impl_field_offsets!(account {
+        POOL_ID_OFFSET: pool_id,
+        START_TICK_INDEX_OFFSET: start_tick_index,
+        TICKS_OFFSET: ticks,
+        INITIALIZED_TICK_COUNT_OFFSET: initialized_tick_count,
+        RECENT_EPOCH_OFFSET: recent_epoch,
+    });
+
+    #[inline(always)]
+    pub fn pool_id(&self) -> &Pubkey {
+        &self.pool_id
+    }
+
+    #[inline(always)]
+    pub fn start_tick_index(&self) -> i32 {
+        i32::from_le_bytes(self.start_tick_index)
+    }
+
+    #[inline(always)]
+    pub fn ticks(&self) -> &[TickState; TICK_ARRAY_SIZE] {
+        &self.ticks
+    }
+
+    /// Tick stored for `tick_index`, or `None` if it lies outside this array
+    /// or is not a multiple of `tick_spacing`.
+    pub fn tick(&self, tick_index: i32, tick_spacing: u16) -> Option<&TickState> {
+        let offset = tick_index.checked_sub(self.start_tick_index())?;
+        let tick_spacing = tick_spacing as i32;
+        if tick_spacing == 0 || offset < 0 || offset % tick_spacing != 0 {
+            return None;
+        }
+        self.ticks.get((offset / tick_spacing) as usize)
+    }
+
+    #[inline(always)]
+    pub fn initialized_tick_count(&self) -> u8 {
+        self.initialized_tick_count
+    }
+
+    #[inline(always)]
+    pub fn recent_epoch(&self) -> u64 {
+        u64::from_le_bytes(self.recent_epoch)
+    }
+}
+
+/// One tick in a `TickArrayState`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TickState {
+    tick: [u8; 4],
+    liquidity_net: [u8; 16],
+    liquidity_gross: [u8; 16],
+    fee_growth_outside_0_x64: [u8; 16],
+    fee_growth_outside_1_x64: [u8; 16],
+    reward_growths_outside_x64: [[u8; 16]; REWARD_NUM],
+    order_phase: [u8; 8],
+    orders_amount: [u8; 8],
+    part_filled_orders_remaining: [u8; 8],
+    unfilled_ratio_x64: [u8; 16],
+    padding: [u8; 12],
+}
+
+const _: () = assert!(core::mem::size_of::<TickState>() == 168);
+
+impl TickState {
+    impl_field_offsets!(nested {
+        TICK_OFFSET: tick,
+        LIQUIDITY_NET_OFFSET: liquidity_net,
+        LIQUIDITY_GROSS_OFFSET: liquidity_gross,
+        FEE_GROWTH_OUTSIDE_0_X64_OFFSET: fee_growth_outside_0_x64,
+        FEE_GROWTH_OUTSIDE_1_X64_OFFSET: fee_growth_outside_1_x64,
+        REWARD_GROWTHS_OUTSIDE_X64_OFFSET: reward_growths_outside_x64,
+        ORDER_PHASE_OFFSET: order_phase,
+        ORDERS_AMOUNT_OFFSET: orders_amount,
+        PART_FILLED_ORDERS_REMAINING_OFFSET: part_filled_orders_remaining,
+        UNFILLED_RATIO_X64_OFFSET: unfilled_ratio_x64,
+    });
+
+    #[inline(always)]
+    pub fn tick(&self) -> i32 {
+        i32::from_le_bytes(self.tick)
+    }
+
+    /// Liquidity added when the price crosses this tick left to right.
+    #[inline(always)]
+    pub fn liquidity_net(&self) -> i128 {
+        i128::from_le_bytes(self.liquidity_net)
+    }
+
+    /// Total liquidity referencing this tick; zero when uninitialized.
+    #[inline(always)]
+    pub fn liquidity_gross(&self) -> u128 {
+        u128::from_le_bytes(self.liquidity_gross)
+    }
+
+    #[inline(always)]
+    pub fn initialized(&self) -> bool {
+        self.liquidity_gross() != 0
+    }
+
+    /// Token 0 fee growth on the other side of this tick from the current tick, as Q64.64.
+    #[inline(always)]
+    pub fn fee_growth_outside_0_x64(&self) -> u128 {
+        u128::from_le_bytes(self.fee_growth_outside_0_x64)
+    }
+
+    /// Token 1 fee growth on the other side of this tick from the current tick, as Q64.64.
+    #[inline(always)]
+    pub fn fee_growth_outside_1_x64(&self) -> u128 {
+        u128::from_le_bytes(self.fee_growth_outside_1_x64)
+    }
+
+    #[inline(always)]
+    pub fn reward_growths_outside_x64(&self) -> [u128; REWARD_NUM] {
+        core::array::from_fn(|i| u128::from_le_bytes(self.reward_growths_outside_x64[i]))
+    }
+
+    #[inline(always)]
+    pub fn order_phase(&self) -> u64 {
+        u64::from_le_bytes(self.order_phase)
+    }
+
+    #[inline(always)]
+    pub fn orders_amount(&self) -> u64 {
+        u64::from_le_bytes(self.orders_amount)
+    }
+
+    #[inline(always)]
+    pub fn part_filled_orders_remaining(&self) -> u64 {
+        u64::from_le_bytes(self.part_filled_orders_remaining)
+    }
+
+    #[inline(always)]
+    pub fn unfilled_ratio_x64(&self) -> u128 {
+        u128::from_le_bytes(self.unfilled_ratio_x64)
+    }
+}
@@ -0,0 +1,339 @@
+use pinocchio::pubkey::Pubkey;
+
+use crate::{
+    discriminator::anchor_discriminator,
+    math::CpPoolAmounts,
+    programs::CPMM_PROGRAM_ID,
+    raydium::{impl_account_loaders, impl_field_offsets},
+};
+
+/// Layout of the CPMM program's `PoolState` account.
+///
+/// Every field is stored as a byte array so the struct has an alignment of 1
+/// and matches the on-chain data byte for byte; use the accessors to read it.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PoolState {
+    amm_config: Pubkey,
+    pool_creator: Pubkey,
+    token_0_vault: Pubkey,
+    token_1_vault: Pubkey,
+    lp_mint: Pubkey,
+    token_0_mint: Pubkey,
+    token_1_mint: Pubkey,
+    token_0_program: Pubkey,
+    token_1_program: Pubkey,
+    observation_key: Pubkey,
+    auth_bump: u8,
+    status: u8,
+    lp_mint_decimals: u8,
+    mint_0_decimals: u8,
+    mint_1_decimals: u8,
+    lp_supply: [u8; 8],
+    protocol_fees_token_0: [u8; 8],
+    protocol_fees_token_1: [u8; 8],
+    fund_fees_token_0: [u8; 8],
+    fund_fees_token_1: [u8; 8],
+    open_time: [u8; 8],
+    recent_epoch: [u8; 8],
+    creator_fee_on: u8,
+    enable_creator_fee: u8,
+    padding1: [u8; 6],
+    creator_fees_token_0: [u8; 8],
+    creator_fees_token_1: [u8; 8],
+    padding: [u8; 224],
+}
+
+const _: () = assert!(PoolState::LEN == 637);
+
+impl PoolState {
+    pub const LEN: usize = core::mem::size_of::<Self>() + 8;
+
+    pub const DISCRIMINATOR: [u8; 8] = anchor_discriminator("account:PoolState");
+
+    impl_account_loaders!(CPMM_PROGRAM_ID);
+
+    impl_field_offsets!(account {
+        AMM_CONFIG_OFFSET: amm_config,
+        POOL_CREATOR_OFFSET: pool_creator,
+        TOKEN_0_VAULT_OFFSET: token_0_vault,
+        TOKEN_1_VAULT_OFFSET: token_1_vault,
+        LP_MINT_OFFSET: lp_mint,
+        TOKEN_0_MINT_OFFSET: token_0_mint,
+        TOKEN_1_MINT_OFFSET: token_1_mint,
+        TOKEN_0_PROGRAM_OFFSET: token_0_program,
+        TOKEN_1_PROGRAM_OFFSET: token_1_program,
+        OBSERVATION_KEY_OFFSET: observation_key,
+        AUTH_BUMP_OFFSET: auth_bump,
+        STATUS_OFFSET: status,
+        LP_MINT_DECIMALS_OFFSET: lp_mint_decimals,
+        MINT_0_DECIMALS_OFFSET: mint_0_decimals,
+        MINT_1_DECIMALS_OFFSET: mint_1_decimals,
+        LP_SUPPLY_OFFSET: lp_supply,
+        PROTOCOL_FEES_TOKEN_0_OFFSET: protocol_fees_token_0,
+        PROTOCOL_FEES_TOKEN_1_OFFSET: protocol_fees_token_1,
+        FUND_FEES_TOKEN_0_OFFSET: fund_fees_token_0,
+        FUND_FEES_TOKEN_1_OFFSET: fund_fees_token_1,
+        OPEN_TIME_OFFSET: open_time,
+        RECENT_EPOCH_OFFSET: recent_epoch,
+        CREATOR_FEE_ON_OFFSET: creator_fee_on,
+        ENABLE_CREATOR_FEE_OFFSET: enable_creator_fee,
+        CREATOR_FEES_TOKEN_0_OFFSET: creator_fees_token_0,
+        CREATOR_FEES_TOKEN_1_OFFSET: creator_fees_token_1,
+    });
+
+    #[inline(always)]
+    pub fn amm_config(&self) -> &Pubkey {
+        &self.amm_config
+    }
+
+    #[inline(always)]
+    pub fn pool_creator(&self) -> &Pubkey {
+        &self.pool_creator
+    }
+
+    #[inline(always)]
+    pub fn token_0_vault(&self) -> &Pubkey {
+        &self.token_0_vault
+    }
+
+    #[inline(always)]
+    pub fn token_1_vault(&self) -> &Pubkey {
+        &self.token_1_vault
+    }
+
+    #[inline(always)]
+    pub fn lp_mint(&self) -> &Pubkey {
+        &self.lp_mint
+    }
+
+    #[inline(always)]
+    pub fn token_0_mint(&self) -> &Pubkey {
+        &self.token_0_mint
+    }
+
+    #[inline(always)]
+    pub fn token_1_mint(&self) -> &Pubkey {
+        &self.token_1_mint
+    }
+
+    /// Token program of `token_0_mint`, SPL Token or Token-2022.
+    #[inline(always)]
+    pub fn token_0_program(&self) -> &Pubkey {
+        &self.token_0_program
+    }
+
+    /// Token program of `token_1_mint`, SPL Token or Token-2022.
+    #[inline(always)]
+    pub fn token_1_program(&self) -> &Pubkey {
+        &self.token_1_program
+    }
+
+    #[inline(always)]
+    pub fn observation_key(&self) -> &Pubkey {
+        &self.observation_key
+    }
+
+    #[inline(always)]
+    pub fn auth_bump(&self) -> u8 {
+        self.auth_bump
+    }
+
+    /// Bit flags disabling deposit (bit 0), withdraw (bit 1) and swap (bit 2).
+    #[inline(always)]
+    pub fn status(&self) -> u8 {
+        self.status
+    }
+
+    #[inline(always)]
+    pub fn lp_mint_decimals(&self) -> u8 {
+        self.lp_mint_decimals
+    }
+
+    #[inline(always)]
+    pub fn mint_0_decimals(&self) -> u8 {
+        self.mint_0_decimals
+    }
+
+    #[inline(always)]
+    pub fn mint_1_decimals(&self) -> u8 {
+        self.mint_1_decimals
+    }
+
+    #[inline(always)]
+    pub fn lp_supply(&self) -> u64 {
+        u64::from_le_bytes(self.lp_supply)
+    }
+
+    #[inline(always)]
+    pub fn protocol_fees_token_0(&self) -> u64 {
+        u64::from_le_bytes(self.protocol_fees_token_0)
+    }
+
+    #[inline(always)]
+    pub fn protocol_fees_token_1(&self) -> u64 {
+        u64::from_le_bytes(self.protocol_fees_token_1)
+    }
+
+    #[inline(always)]
+    pub fn fund_fees_token_0(&self) -> u64 {
+        u64::from_le_bytes(self.fund_fees_token_0)
+    }
+
+    #[inline(always)]
+    pub fn fund_fees_token_1(&self) -> u64 {
+        u64::from_le_bytes(self.fund_fees_token_1)
+    }
+
+    #[inline(always)]
+    pub fn open_time(&self) -> u64 {
+        u64::from_le_bytes(self.open_time)
+    }
+
+    #[inline(always)]
+    pub fn recent_epoch(&self) -> u64 {
+        u64::from_le_bytes(self.recent_epoch)
+    }
+
+    /// Which side creator fees are charged on.
+    #[inline(always)]
+    pub fn creator_fee_on(&self) -> u8 {
+        self.creator_fee_on
+    }
+
+    #[inline(always)]
+    pub fn enable_creator_fee(&self) -> bool {
+        self.enable_creator_fee != 0
+    }
+
+    #[inline(always)]
+    pub fn creator_fees_token_0(&self) -> u64 {
+        u64::from_le_bytes(self.creator_fees_token_0)
+    }
+
+    #[inline(always)]
+    pub fn creator_fees_token_1(&self) -> u64 {
+        u64::from_le_bytes(self.creator_fees_token_1)
+    }
+
+    /// Pool amounts for `math`, from the vault token account balances minus
+    /// the protocol, fund and creator fees they still hold.
+    pub fn pool_amounts(
+        &self,
+        token_0_vault_balance: u64,
+        token_1_vault_balance: u64,
+    ) -> Option<CpPoolAmounts> {
+        let token_0_fees = self
+            .protocol_fees_token_0()
+            .checked_add(self.fund_fees_token_0())?
+            .checked_add(self.creator_fees_token_0())?;
+        let token_1_fees = self
+            .protocol_fees_token_1()
+            .checked_add(self.fund_fees_token_1())?
+            .checked_add(self.creator_fees_token_1())?;
+
+        Some(CpPoolAmounts {
+            token_0_vault_amount: token_0_vault_balance.checked_sub(token_0_fees)?,
+            token_1_vault_amount: token_1_vault_balance.checked_sub(token_1_fees)?,
+            lp_supply: self.lp_supply(),
+        })
+    }
+}
+
+/// Layout of the CPMM program's `AmmConfig` account.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AmmConfig {
+    bump: u8,
+    disable_create_pool: u8,
+    index: [u8; 2],
+    trade_fee_rate: [u8; 8],
+    protocol_fee_rate: [u8; 8],
+    fund_fee_rate: [u8; 8],
+    create_pool_fee: [u8; 8],
+    protocol_owner: Pubkey,
+    fund_owner: Pubkey,
+    creator_fee_rate: [u8; 8],
+    padding: [u8; 120],
+}
+
+const _: () = assert!(AmmConfig::LEN == 236);
+
+impl AmmConfig {
+    pub const LEN: usize = core::mem::size_of::<Self>() + 8;
+
+    pub const DISCRIMINATOR: [u8; 8] = anchor_discriminator("account:AmmConfig");
+
+    /// Denominator of the `*_rate` fields.
+    pub const FEE_RATE_DENOMINATOR: u64 = 1_000_000;
+
+    impl_account_loaders!(CPMM_PROGRAM_ID);
+
+    impl_field_offsets!(account {
+        BUMP_OFFSET: bump,
+        DISABLE_CREATE_POOL_OFFSET: disable_create_pool,
+        INDEX_OFFSET: index,
+        TRADE_FEE_RATE_OFFSET: trade_fee_rate,
+        PROTOCOL_FEE_RATE_OFFSET: protocol_fee_rate,
+        FUND_FEE_RATE_OFFSET: fund_fee_rate,
+        CREATE_POOL_FEE_OFFSET: create_pool_fee,
+        PROTOCOL_OWNER_OFFSET: protocol_owner,
+        FUND_OWNER_OFFSET: fund_owner,
+        CREATOR_FEE_RATE_OFFSET: creator_fee_rate,
+    });
+
+    #[inline(always)]
+    pub fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    #[inline(always)]
+    pub fn disable_create_pool(&self) -> bool {
+        self.disable_create_pool != 0
+    }
+
+    #[inline(always)]
+    pub fn index(&self) -> u16 {
+        u16::from_le_bytes(self.index)
+    }
+
+    /// Swap fee, in `FEE_RATE_DENOMINATOR` units of the input amount.
+    #[inline(always)]
+    pub fn trade_fee_rate(&self) -> u64 {
+        u64::from_le_bytes(self.trade_fee_rate)
+    }
+
+    /// Protocol share of the trade fee.
+    #[inline(always)]
+    pub fn protocol_fee_rate(&self) -> u64 {
+        u64::from_le_bytes(self.protocol_fee_rate)
+    }
+
+    /// Fund share of the trade fee.
+    #[inline(always)]
+    pub fn fund_fee_rate(&self) -> u64 {
+        u64::from_le_bytes(self.fund_fee_rate)
+    }
+
+    /// Lamports charged to create a pool.
+    #[inline(always)]
+    pub fn create_pool_fee(&self) -> u64 {
+        u64::from_le_bytes(self.create_pool_fee)
+    }
+
+    #[inline(always)]
+    pub fn protocol_owner(&self) -> &Pubkey {
+        &self.protocol_owner
+    }
+
+    #[inline(always)]
+    pub fn fund_owner(&self) -> &Pubkey {
+        &self.fund_owner
+    }
+
+    /// Creator fee, in `FEE_RATE_DENOMINATOR` units of the input amount.
+    #[inline(always)]
+    pub fn creator_fee_rate(&self) -> u64 {
+        u64::from_le_bytes(self.creator_fee_rate)
+    }
+}
@@ -0,0 +1,91 @@
+//! Zero-copy views over Raydium program accounts used alongside the lock program.
+
+pub mod clmm;
+pub mod cpmm;
+
+// Implements `from_account_info`, `from_account_info_unchecked` and `from_bytes`
+// for a view with `LEN` and `DISCRIMINATOR` constants, owned by `$owner`.
+macro_rules! impl_account_loaders {
+    ($owner:expr) => {
+        /// Borrows the account data as this view.
+        ///
+        /// Fails with `LockingError::InvalidAccountLength`, `InvalidAccountOwner` or
+        /// `InvalidAccountDiscriminator` as `ProgramError::Custom`, rather than the
+        /// generic `ProgramError::InvalidAccountData`/`InvalidAccountOwner`.
+        #[inline]
+        pub fn from_account_info(
+            account_info: &pinocchio::account_info::AccountInfo,
+        ) -> Result<pinocchio::account_info::Ref<'_, Self>, pinocchio::program_error::ProgramError>
+        {
+            if account_info.data_len() != Self::LEN {
+                return Err($crate::error::LockingError::InvalidAccountLength.into());
+            }
+            if !account_info.is_owned_by(&$owner) {
+                return Err($crate::error::LockingError::InvalidAccountOwner.into());
+            }
+            let data = account_info.try_borrow_data()?;
+            if data[..8] != Self::DISCRIMINATOR {
+                return Err($crate::error::LockingError::InvalidAccountDiscriminator.into());
+            }
+            Ok(pinocchio::account_info::Ref::map(data, |data| unsafe {
+                Self::from_bytes(&data[8..])
+            }))
+        }
+
+        /// Same checks and errors as `from_account_info`, without borrow tracking.
+        ///
+        /// # Safety
+        ///
+        /// The caller must ensure that it is safe to borrow the account data, e.g., there are
+        /// no mutable borrows of the account data.
+        #[inline]
+        pub unsafe fn from_account_info_unchecked(
+            account_info: &pinocchio::account_info::AccountInfo,
+        ) -> Result<&Self, pinocchio::program_error::ProgramError> {
+            if account_info.data_len() != Self::LEN {
+                return Err($crate::error::LockingError::InvalidAccountLength.into());
+            }
+            if account_info.owner() != &$owner {
+                return Err($crate::error::LockingError::InvalidAccountOwner.into());
+            }
+            let data = account_info.borrow_data_unchecked();
+            if data[..8] != Self::DISCRIMINATOR {
+                return Err($crate::error::LockingError::InvalidAccountDiscriminator.into());
+            }
+            Ok(Self::from_bytes(&data[8..]))
+        }
+
+        /// # Safety
+        ///
+        /// The caller must ensure that `bytes` contains a valid representation of the account
+        /// (without the 8-byte discriminator) and is at least `LEN - 8` bytes long.
+        #[inline(always)]
+        pub unsafe fn from_bytes(bytes: &[u8]) -> &Self {
+            &*(bytes.as_ptr() as *const Self)
+        }
+    };
+}
+
+pub(crate) use impl_account_loaders;
+
+// Declares a `usize` offset constant per field, counted from the start of the
+// account data for an account view or from the start of the value for a type
+// nested in one.
+macro_rules! impl_field_offsets {
+    (account { $($name:ident: $field:ident),* $(,)? }) => {
+        $(
+            #[doc = concat!(
+                "Offset of `", stringify!($field), "` from the start of the account data."
+            )]
+            pub const $name: usize = 8 + core::mem::offset_of!(Self, $field);
+        )*
+    };
+    (nested { $($name:ident: $field:ident),* $(,)? }) => {
+        $(
+            #[doc = concat!("Offset of `", stringify!($field), "` from the start of the value.")]
+            pub const $name: usize = core::mem::offset_of!(Self, $field);
+        )*
+    };
+}
+
+pub(crate) use impl_field_offsets;
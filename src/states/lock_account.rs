@@ -0,0 +1,61 @@
+use pinocchio::{
+    account_info::{AccountInfo, Ref},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    error::LockingError,
+    states::{LockedClmmPositionState, LockedCpLiquidityState},
+    ID,
+};
+
+/// Typed view over any state account owned by the lock program, selected by
+/// its Anchor account discriminator.
+pub enum LockAccount<'a> {
+    CpLiquidity(Ref<'a, LockedCpLiquidityState>),
+    ClmmPosition(Ref<'a, LockedClmmPositionState>),
+}
+
+impl<'a> LockAccount<'a> {
+    /// Loads whichever lock state `account_info` holds, failing with the same
+    /// `LockingError`s as the state loaders.
+    pub fn from_account_info(account_info: &'a AccountInfo) -> Result<Self, ProgramError> {
+        if !account_info.is_owned_by(&ID) {
+            return Err(LockingError::InvalidAccountOwner.into());
+        }
+
+        let discriminator: [u8; 8] = {
+            let data = account_info.try_borrow_data()?;
+            data.get(..8)
+                .and_then(|d| d.try_into().ok())
+                .ok_or(LockingError::InvalidAccountLength)?
+        };
+
+        match discriminator {
+            LockedCpLiquidityState::DISCRIMINATOR => {
+                LockedCpLiquidityState::from_account_info(account_info).map(Self::CpLiquidity)
+            }
+            LockedClmmPositionState::DISCRIMINATOR => {
+                LockedClmmPositionState::from_account_info(account_info).map(Self::ClmmPosition)
+            }
+            _ => Err(LockingError::InvalidAccountDiscriminator.into()),
+        }
+    }
+
+    #[inline(always)]
+    pub fn pool_id(&self) -> &Pubkey {
+        match self {
+            Self::CpLiquidity(state) => state.pool_id(),
+            Self::ClmmPosition(state) => state.pool_id(),
+        }
+    }
+
+    #[inline(always)]
+    pub fn fee_nft_mint(&self) -> &Pubkey {
+        match self {
+            Self::CpLiquidity(state) => state.fee_nft_mint(),
+            Self::ClmmPosition(state) => state.fee_nft_mint(),
+        }
+    }
+}
@@ -1,5 +1,7 @@
-pub mod locked_cp_liquidity_state;
+pub mod lock_account;
 pub mod locked_clmm_position_state;
+pub mod locked_cp_liquidity_state;
 
+pub use lock_account::*;
 pub use locked_clmm_position_state::*;
-pub use locked_cp_liquidity_state::*;
\ No newline at end of file
+pub use locked_cp_liquidity_state::*;
@@ -0,0 +1,144 @@
+use core::{ops::Deref, ptr::NonNull};
+use std::{
+    alloc::{self, Layout},
+    vec::Vec,
+};
+
+use pinocchio::{
+    account_info::{AccountInfo, MAX_PERMITTED_DATA_INCREASE},
+    pubkey::Pubkey,
+};
+
+use crate::{instructions::AccountRole, programs::SYSTEM_PROGRAM_ID};
+
+// Offsets into the account header the runtime serializes before the data,
+// which `AccountInfo` points at.
+const IS_SIGNER: usize = 1;
+const IS_WRITABLE: usize = 2;
+const EXECUTABLE: usize = 3;
+const KEY: usize = 8;
+const OWNER: usize = 40;
+const LAMPORTS: usize = 72;
+const DATA_LEN: usize = 80;
+const HEADER_LEN: usize = 88;
+
+// `AccountInfo` is a single pointer to the header
+const _: () = assert!(core::mem::size_of::<AccountInfo>() == core::mem::size_of::<*mut u8>());
+
+/// An account laid out the way the runtime hands it to a program, for
+/// building `AccountInfo`s in native tests.
+///
+/// The data is followed by the 10 KiB the runtime reserves for `realloc`, so
+/// instructions that resize accounts can be exercised too.
+pub struct TestAccount {
+    raw: NonNull<u8>,
+    layout: Layout,
+}
+
+impl TestAccount {
+    /// Creates a read-only, non-signer account holding a copy of `data`.
+    pub fn new(key: Pubkey, owner: Pubkey, lamports: u64, data: &[u8]) -> Self {
+        let size = HEADER_LEN + data.len() + MAX_PERMITTED_DATA_INCREASE;
+        let layout = Layout::from_size_align(size, 8).unwrap();
+        // SAFETY: `layout` has a non-zero size.
+        let raw = NonNull::new(unsafe { alloc::alloc_zeroed(layout) })
+            .unwrap_or_else(|| alloc::handle_alloc_error(layout));
+
+        let mut account = Self { raw, layout };
+        let header = account.header_mut();
+        header[KEY..KEY + 32].copy_from_slice(&key);
+        header[OWNER..OWNER + 32].copy_from_slice(&owner);
+        header[LAMPORTS..LAMPORTS + 8].copy_from_slice(&lamports.to_le_bytes());
+        header[DATA_LEN..DATA_LEN + 8].copy_from_slice(&(data.len() as u64).to_le_bytes());
+        // SAFETY: the allocation holds `data.len()` bytes after the header.
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                data.as_ptr(),
+                account.raw.as_ptr().add(HEADER_LEN),
+                data.len(),
+            );
+        }
+        account
+    }
+
+    /// Creates an empty, system-owned account at `key` with the signer and
+    /// writable flags of `role`.
+    pub fn for_role(key: Pubkey, role: &AccountRole) -> Self {
+        let mut account = Self::new(key, SYSTEM_PROGRAM_ID, 1, &[]);
+        if role.is_signer {
+            account = account.signer();
+        }
+        if role.is_writable {
+            account = account.writable();
+        }
+        account
+    }
+
+    /// Sets the program that owns the account.
+    pub fn owned_by(mut self, owner: Pubkey) -> Self {
+        self.header_mut()[OWNER..OWNER + 32].copy_from_slice(&owner);
+        self
+    }
+
+    /// Marks the account as a transaction signer.
+    pub fn signer(mut self) -> Self {
+        self.header_mut()[IS_SIGNER] = 1;
+        self
+    }
+
+    /// Marks the account as writable.
+    pub fn writable(mut self) -> Self {
+        self.header_mut()[IS_WRITABLE] = 1;
+        self
+    }
+
+    /// Marks the account as an executable program.
+    pub fn executable(mut self) -> Self {
+        self.header_mut()[EXECUTABLE] = 1;
+        self
+    }
+
+    /// The account as a program receives it.
+    #[inline(always)]
+    pub fn account_info(&self) -> &AccountInfo {
+        // SAFETY: `AccountInfo` only wraps the header pointer.
+        unsafe { &*(&self.raw as *const NonNull<u8> as *const AccountInfo) }
+    }
+
+    fn header_mut(&mut self) -> &mut [u8] {
+        // SAFETY: `&mut self` rules out live `AccountInfo` borrows.
+        unsafe { core::slice::from_raw_parts_mut(self.raw.as_ptr(), HEADER_LEN) }
+    }
+}
+
+impl Deref for TestAccount {
+    type Target = AccountInfo;
+
+    #[inline(always)]
+    fn deref(&self) -> &AccountInfo {
+        self.account_info()
+    }
+}
+
+impl Drop for TestAccount {
+    fn drop(&mut self) {
+        // SAFETY: allocated in `new` with the same layout.
+        unsafe { alloc::dealloc(self.raw.as_ptr(), self.layout) }
+    }
+}
+
+/// The `AccountInfo`s of `accounts`, as the slice a program's entrypoint
+/// receives.
+pub fn infos(accounts: &[TestAccount]) -> Vec<AccountInfo> {
+    accounts
+        .iter()
+        .map(|account| account.account_info().clone())
+        .collect()
+}
+
+/// Minimum balance for an account of `data_len` bytes to be rent exempt,
+/// at the default rent of 3,480 lamports per byte-year over two years.
+pub const fn rent_exempt_lamports(data_len: usize) -> u64 {
+    // The runtime charges for 128 bytes of account metadata too
+    (128 + data_len as u64) * 3_480 * 2
+}
@@ -0,0 +1,306 @@
+use core::{cell::RefCell, marker::PhantomData};
+use std::{boxed::Box, vec::Vec};
+
+use litesvm::LiteSVM;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta as CpiAccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+use solana_instruction::error::InstructionError;
+use solana_program_runtime::declare_process_instruction;
+
+use crate::{
+    client::AccountMeta,
+    decode::{DecodedInstruction, LockingInstruction},
+    error::{LockProgramError, LockingError},
+    pda,
+    programs::SYSTEM_PROGRAM_ID,
+    raydium::clmm::PersonalPositionState,
+    states::{
+        LockedClmmPositionState, LockedCpLiquidityState, LOCKED_LIQUIDITY_SEED,
+        LOCKED_POSITION_SEED,
+    },
+    test_utils::{deploy_native, rent_exempt_lamports, run_native, NativeCpi},
+    ID,
+};
+
+/// An instruction received by the mock lock program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordedCpi {
+    /// The decoded instruction, if the data held a known discriminator.
+    pub instruction: Option<LockingInstruction>,
+    /// Accounts with the privileges the mock received them with.
+    pub accounts: Vec<AccountMeta>,
+    pub data: Vec<u8>,
+    /// 1 for a top-level instruction, 2 or more for a CPI.
+    pub stack_height: usize,
+    pub result: Result<(), InstructionError>,
+}
+
+std::thread_local! {
+    static RECEIVED: RefCell<Vec<RecordedCpi>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Stand-in for the lock program, deployed at [`crate::ID`] in a LiteSVM
+/// instance as a native builtin.
+///
+/// It checks the discriminator, arguments, account roles and lock program
+/// PDAs, creates the lock state accounts with their real layouts through the
+/// system program like Anchor's `init`, and updates them. It does not move
+/// tokens, mint the fee NFT or read the Raydium pools, so fields the lock
+/// program derives from them (`last_lp`, `last_k`, `recent_epoch`) stay zero.
+///
+/// SBF programs under test reach it with their usual `invoke`/`invoke_signed`
+/// CPIs, native ones through [`NativeCpi`], with the runtime checking signers
+/// and privileges. Builtins cannot hold state, so received instructions are
+/// recorded per thread, the one LiteSVM executes transactions on.
+pub struct MockLockProgram {
+    _not_send: PhantomData<*const ()>,
+}
+
+impl MockLockProgram {
+    /// Deploys the mock at `crate::ID` and clears the record of this thread.
+    pub fn deploy(svm: &mut LiteSVM) -> Self {
+        deploy_native(svm, &ID, Entrypoint::vm);
+        RECEIVED.with_borrow_mut(Vec::clear);
+        Self {
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Instructions received so far, in order.
+    pub fn cpis(&self) -> Vec<RecordedCpi> {
+        RECEIVED.with_borrow(Vec::clone)
+    }
+
+    /// Returns the instructions received so far and clears the record.
+    pub fn take_cpis(&self) -> Vec<RecordedCpi> {
+        RECEIVED.with_borrow_mut(core::mem::take)
+    }
+}
+
+// Builtins must consume compute units; 150 is what the system program charges
+declare_process_instruction!(Entrypoint, 150, |invoke_context| {
+    let transaction_context = &invoke_context.transaction_context;
+    let instruction_context = transaction_context.get_current_instruction_context()?;
+    let accounts = (0..instruction_context.get_number_of_instruction_accounts())
+        .map(|index| {
+            let key = transaction_context.get_key_of_account_at_index(
+                instruction_context.get_index_of_instruction_account_in_transaction(index)?,
+            )?;
+            Ok(AccountMeta {
+                pubkey: key.to_bytes(),
+                is_signer: instruction_context.is_instruction_account_signer(index)?,
+                is_writable: instruction_context.is_instruction_account_writable(index)?,
+            })
+        })
+        .collect::<Result<Vec<_>, InstructionError>>()?;
+    let data = instruction_context.get_instruction_data().to_vec();
+    let stack_height = transaction_context.get_instruction_context_stack_height();
+
+    let result = run_native(invoke_context, process_instruction);
+
+    RECEIVED.with_borrow_mut(|received| {
+        received.push(RecordedCpi {
+            instruction: LockingInstruction::unpack(&data).ok(),
+            accounts,
+            data,
+            stack_height,
+            result: result.clone(),
+        })
+    });
+    result
+});
+
+fn process_instruction(
+    cpi: &mut NativeCpi,
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    if program_id != &ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let instruction = LockingInstruction::unpack(data).map_err(|error| match error {
+        LockingError::UnknownInstructionDiscriminator => {
+            anchor_error(LockProgramError::InstructionFallbackNotFound)
+        }
+        _ => anchor_error(LockProgramError::InstructionDidNotDeserialize),
+    })?;
+
+    let roles = instruction.accounts();
+    if accounts.len() < roles.len() {
+        return Err(anchor_error(LockProgramError::AccountNotEnoughKeys));
+    }
+    for (role, account) in roles.iter().zip(accounts) {
+        if role.is_signer && !account.is_signer() {
+            return Err(anchor_error(LockProgramError::AccountNotSigner));
+        }
+        if role.is_writable && !account.is_writable() {
+            return Err(anchor_error(LockProgramError::ConstraintMut));
+        }
+    }
+
+    let decoded = DecodedInstruction {
+        instruction,
+        accounts,
+    };
+    let not_enough_keys = || anchor_error(LockProgramError::AccountNotEnoughKeys);
+
+    match instruction {
+        LockingInstruction::LockCpLiquidity { lp_amount, .. } => {
+            let accounts = decoded.lock_cp_liquidity().ok_or_else(not_enough_keys)?;
+            check_address(accounts.authority(), &pda::find_lock_cp_authority().0)?;
+            let fee_nft_mint = accounts.fee_nft_mint().key();
+            let locked_liquidity = accounts.locked_liquidity();
+            let (address, bump) = pda::find_locked_liquidity(fee_nft_mint);
+            check_address(locked_liquidity, &address)?;
+
+            let seeds: &[&[u8]] = &[LOCKED_LIQUIDITY_SEED.as_bytes(), fee_nft_mint, &[bump]];
+            let space = LockedCpLiquidityState::LEN;
+            init_state(
+                cpi,
+                accounts.payer(),
+                locked_liquidity,
+                seeds,
+                space,
+                |data| {
+                    data[..8].copy_from_slice(&LockedCpLiquidityState::DISCRIMINATOR);
+                    // SAFETY: `data` is `LEN` bytes long and not borrowed elsewhere.
+                    let state = unsafe { LockedCpLiquidityState::from_bytes_mut(&mut data[8..]) };
+                    state.set_locked_lp_amount(lp_amount);
+                    state.set_pool_id(*accounts.pool_state().key());
+                    state.set_fee_nft_mint(*fee_nft_mint);
+                    state.set_locked_owner(*accounts.liquidity_owner().key());
+                    state.set_locked_lp_mint(*accounts.lp_mint().key());
+                },
+            )
+        }
+        LockingInstruction::LockClmmPosition { .. } => {
+            let accounts = decoded.lock_clmm_position().ok_or_else(not_enough_keys)?;
+            check_address(accounts.authority(), &pda::find_lock_clmm_authority().0)?;
+            let fee_nft_mint = accounts.fee_nft_mint().key();
+            let locked_position = accounts.locked_position();
+            let (address, bump) = pda::find_locked_position(fee_nft_mint);
+            check_address(locked_position, &address)?;
+
+            let personal_position = accounts.personal_position();
+            let pool_id = *PersonalPositionState::from_account_info(personal_position)
+                .map_err(account_error)?
+                .pool_id();
+
+            let seeds: &[&[u8]] = &[LOCKED_POSITION_SEED.as_bytes(), fee_nft_mint, &[bump]];
+            let space = LockedClmmPositionState::LEN;
+            init_state(
+                cpi,
+                accounts.payer(),
+                locked_position,
+                seeds,
+                space,
+                |data| {
+                    data[..8].copy_from_slice(&LockedClmmPositionState::DISCRIMINATOR);
+                    // SAFETY: `data` is `LEN` bytes long and not borrowed elsewhere.
+                    let state = unsafe { LockedClmmPositionState::from_bytes_mut(&mut data[8..]) };
+                    state.set_bump(bump);
+                    state.set_position_owner(*accounts.position_nft_owner().key());
+                    state.set_pool_id(pool_id);
+                    state.set_position_id(*personal_position.key());
+                    state.set_locked_nft_account(*accounts.locked_nft_account().key());
+                    state.set_fee_nft_mint(*fee_nft_mint);
+                },
+            )
+        }
+        LockingInstruction::CollectCpFee { fee_lp_amount } => {
+            let accounts = decoded.collect_cp_fee().ok_or_else(not_enough_keys)?;
+            check_address(accounts.authority(), &pda::find_lock_cp_authority().0)?;
+            let locked_liquidity = accounts.locked_liquidity();
+            LockedCpLiquidityState::from_account_info(locked_liquidity).map_err(account_error)?;
+
+            let mut data = locked_liquidity.try_borrow_mut_data()?;
+            // SAFETY: the account was loaded above, so it holds `LEN` bytes.
+            let state = unsafe { LockedCpLiquidityState::from_bytes_mut(&mut data[8..]) };
+            let claimed = fee_lp_amount.min(state.unclaimed_lp_amount());
+            state.set_unclaimed_lp_amount(state.unclaimed_lp_amount() - claimed);
+            state.set_claimed_lp_amount(state.claimed_lp_amount() + claimed);
+            Ok(())
+        }
+        LockingInstruction::CollectClmmFeeAndReward => {
+            let accounts = decoded
+                .collect_clmm_fee_and_reward()
+                .ok_or_else(not_enough_keys)?;
+            check_address(accounts.authority(), &pda::find_lock_clmm_authority().0)?;
+            LockedClmmPositionState::from_account_info(accounts.locked_position())
+                .map_err(account_error)?;
+            Ok(())
+        }
+    }
+}
+
+// Creates the lock program account at the PDA of `seeds` with `space` bytes,
+// funded by `payer`, like Anchor's `init`
+fn init_state(
+    cpi: &mut NativeCpi,
+    payer: &AccountInfo,
+    account: &AccountInfo,
+    seeds: &[&[u8]],
+    space: usize,
+    write: impl FnOnce(&mut [u8]),
+) -> ProgramResult {
+    if account.is_owned_by(&ID) {
+        return Err(anchor_error(
+            LockProgramError::AccountDiscriminatorAlreadySet,
+        ));
+    }
+
+    // System program `CreateAccount`: index, lamports, space, owner
+    let mut data = [0; 52];
+    data[4..12].copy_from_slice(&rent_exempt_lamports(space).to_le_bytes());
+    data[12..20].copy_from_slice(&(space as u64).to_le_bytes());
+    data[20..].copy_from_slice(&ID);
+    let accounts = [
+        CpiAccountMeta::writable_signer(payer.key()),
+        CpiAccountMeta::writable_signer(account.key()),
+    ];
+    let create_account = Instruction {
+        program_id: &SYSTEM_PROGRAM_ID,
+        accounts: &accounts,
+        data: &data,
+    };
+    cpi.invoke_signed(&create_account, &[seeds])?;
+
+    write(&mut account.try_borrow_mut_data()?);
+    Ok(())
+}
+
+fn check_address(account: &AccountInfo, expected: &Pubkey) -> ProgramResult {
+    if account.key() != expected {
+        return Err(anchor_error(LockProgramError::ConstraintSeeds));
+    }
+    Ok(())
+}
+
+// Maps this crate's account loader errors to the Anchor errors the lock
+// program returns for the same account.
+fn account_error(error: ProgramError) -> ProgramError {
+    const OWNER: u32 = LockingError::InvalidAccountOwner.code();
+    const DISCRIMINATOR: u32 = LockingError::InvalidAccountDiscriminator.code();
+    const LENGTH: u32 = LockingError::InvalidAccountLength.code();
+
+    match error {
+        ProgramError::Custom(OWNER) => anchor_error(LockProgramError::AccountOwnedByWrongProgram),
+        ProgramError::Custom(DISCRIMINATOR) => {
+            anchor_error(LockProgramError::AccountDiscriminatorMismatch)
+        }
+        ProgramError::Custom(LENGTH) => anchor_error(LockProgramError::AccountDidNotDeserialize),
+        error => error,
+    }
+}
+
+#[inline(always)]
+fn anchor_error(error: LockProgramError) -> ProgramError {
+    ProgramError::Custom(error.code())
+}
@@ -0,0 +1,22 @@
+//! Native test support, behind the `test-utils` feature.
+//!
+//! [`TestAccount`] builds `AccountInfo`s laid out the way the runtime passes
+//! them, for unit tests of code that reads accounts.
+//!
+//! With the `mock-program` feature, [`MockLockProgram`] stands in for the lock
+//! program in a LiteSVM instance, so the CPIs issued by
+//! [`crate::instructions`] can be exercised end to end with `cargo test`, and
+//! [`deploy_native`] and [`run_native`] run pinocchio program code as a native
+//! builtin next to it.
+
+mod account;
+#[cfg(feature = "mock-program")]
+mod mock;
+#[cfg(feature = "mock-program")]
+mod native;
+
+pub use account::{infos, rent_exempt_lamports, TestAccount};
+#[cfg(feature = "mock-program")]
+pub use mock::{MockLockProgram, RecordedCpi};
+#[cfg(feature = "mock-program")]
+pub use native::{deploy_native, run_native, NativeCpi};
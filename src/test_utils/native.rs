@@ -0,0 +1,209 @@
+use core::{mem::MaybeUninit, slice};
+use std::vec::Vec;
+
+use litesvm::LiteSVM;
+use pinocchio::{
+    account_info::AccountInfo, entrypoint::deserialize, instruction::Instruction,
+    program_error::ProgramError, pubkey::Pubkey, ProgramResult, MAX_TX_ACCOUNTS,
+};
+use solana_account::Account;
+use solana_instruction::error::InstructionError;
+use solana_program_runtime::{
+    invoke_context::{BuiltinFunctionWithContext, InvokeContext},
+    serialization::serialize_parameters,
+};
+use solana_sdk_ids::native_loader;
+
+use crate::pda;
+
+/// Deploys `entrypoint` at `program_id` in `svm` as a native builtin.
+///
+/// LiteSVM's `add_builtin` leaves the program account owned by the BPF loader,
+/// which only runs SBF programs, so the account is handed to the native loader
+/// like the runtime's own builtins.
+pub fn deploy_native(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    entrypoint: BuiltinFunctionWithContext,
+) {
+    let program_id = solana_pubkey::Pubkey::new_from_array(*program_id);
+    svm.add_builtin(program_id, entrypoint);
+
+    let account = Account {
+        lamports: 1,
+        data: Vec::new(),
+        owner: native_loader::ID,
+        executable: true,
+        rent_epoch: 0,
+    };
+    svm.set_account(program_id, account)
+        .expect("native loader accounts are not loaded as programs");
+}
+
+/// Runs pinocchio program code as a native builtin in an SVM such as LiteSVM.
+///
+/// The current instruction's accounts are serialized the way the SBF loader
+/// passes them to a program, `process` sees them as `AccountInfo`s, and any
+/// changes it makes are written back through the runtime's account checks:
+/// only the owner may change data or debit lamports, only writable accounts
+/// change at all.
+pub fn run_native(
+    invoke_context: &mut InvokeContext,
+    process: impl FnOnce(&mut NativeCpi, &Pubkey, &[AccountInfo], &[u8]) -> ProgramResult,
+) -> Result<(), InstructionError> {
+    let (mut input, _, _) = {
+        let transaction_context = &invoke_context.transaction_context;
+        let instruction_context = transaction_context.get_current_instruction_context()?;
+        serialize_parameters(transaction_context, instruction_context, true, false)?
+    };
+
+    let mut accounts = [const { MaybeUninit::<AccountInfo>::uninit() }; MAX_TX_ACCOUNTS];
+    // SAFETY: `input` is the loader's aligned serialization, including the
+    // realloc padding after each account, and outlives `accounts`.
+    let (program_id, count, data) =
+        unsafe { deserialize::<MAX_TX_ACCOUNTS>(input.as_slice_mut().as_mut_ptr(), &mut accounts) };
+    // SAFETY: `deserialize` initialized the first `count` accounts.
+    let accounts = unsafe { slice::from_raw_parts(accounts.as_ptr() as *const AccountInfo, count) };
+
+    let mut cpi = NativeCpi {
+        invoke_context,
+        program_id: *program_id,
+        accounts,
+        error: None,
+    };
+    let result = process(&mut cpi, program_id, accounts, data);
+
+    if let Some(error) = cpi.error {
+        return Err(error);
+    }
+    result.map_err(|error| InstructionError::from(u64::from(error)))?;
+    write_back(cpi.invoke_context, accounts)
+}
+
+/// Cross-program invocations from code running under [`run_native`].
+pub struct NativeCpi<'a, 'b> {
+    invoke_context: &'a mut InvokeContext<'b>,
+    program_id: Pubkey,
+    accounts: &'a [AccountInfo],
+    error: Option<InstructionError>,
+}
+
+impl NativeCpi<'_, '_> {
+    /// Invokes `instruction` through the runtime, which checks the privileges
+    /// it passes on. Each entry of `signers_seeds` signs for the address it
+    /// derives under the running program, like `invoke_signed`.
+    ///
+    /// As on-chain, a failed invocation fails the calling instruction with the
+    /// callee's error, whatever the caller returns afterwards.
+    pub fn invoke_signed(
+        &mut self,
+        instruction: &Instruction,
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        let signers = signers_seeds
+            .iter()
+            .map(|seeds| pda::create_program_address(seeds, &self.program_id))
+            .map(|signer| signer.map(solana_pubkey::Pubkey::new_from_array))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let instruction = solana_instruction::Instruction {
+            program_id: solana_pubkey::Pubkey::new_from_array(*instruction.program_id),
+            accounts: instruction
+                .accounts
+                .iter()
+                .map(|meta| solana_instruction::AccountMeta {
+                    pubkey: solana_pubkey::Pubkey::new_from_array(*meta.pubkey),
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                })
+                .collect(),
+            data: instruction.data.to_vec(),
+        };
+
+        // The callee sees the caller's changes so far, and the caller its
+        // changes once it returns
+        let result = write_back(self.invoke_context, self.accounts)
+            .and_then(|()| {
+                self.invoke_context
+                    .native_invoke(instruction.into(), &signers)
+            })
+            .and_then(|()| refresh(self.invoke_context, self.accounts));
+
+        result.map_err(|error| {
+            // Runtime errors such as `PrivilegeEscalation` have no program error
+            let program_error = solana_program_error::ProgramError::try_from(error.clone())
+                .map_or(ProgramError::InvalidArgument, |error| {
+                    u64::from(error).into()
+                });
+            self.error = Some(error);
+            program_error
+        })
+    }
+}
+
+// Applies the changes made to `accounts` to the current instruction's accounts
+fn write_back(
+    invoke_context: &InvokeContext,
+    accounts: &[AccountInfo],
+) -> Result<(), InstructionError> {
+    let transaction_context = &invoke_context.transaction_context;
+    let instruction_context = transaction_context.get_current_instruction_context()?;
+
+    for (index, info) in (0..).zip(accounts) {
+        if instruction_context
+            .is_instruction_account_duplicate(index)?
+            .is_some()
+        {
+            continue;
+        }
+        let mut account =
+            instruction_context.try_borrow_instruction_account(transaction_context, index)?;
+
+        if account.get_lamports() != info.lamports() {
+            account.set_lamports(info.lamports())?;
+        }
+        let data = info
+            .try_borrow_data()
+            .map_err(|_| InstructionError::AccountBorrowFailed)?;
+        if account.get_data() != &*data {
+            account.set_data_from_slice(&data)?;
+        }
+        // SAFETY: nothing reassigns the account while the owner is read.
+        let owner = unsafe { info.owner() };
+        if account.get_owner().as_array() != owner {
+            account.set_owner(owner)?;
+        }
+    }
+    Ok(())
+}
+
+// Reloads `accounts` from the current instruction's accounts after a CPI
+fn refresh(
+    invoke_context: &InvokeContext,
+    accounts: &[AccountInfo],
+) -> Result<(), InstructionError> {
+    let transaction_context = &invoke_context.transaction_context;
+    let instruction_context = transaction_context.get_current_instruction_context()?;
+
+    for (index, info) in (0..).zip(accounts) {
+        if instruction_context
+            .is_instruction_account_duplicate(index)?
+            .is_some()
+        {
+            continue;
+        }
+        let account =
+            instruction_context.try_borrow_instruction_account(transaction_context, index)?;
+
+        let borrow_failed = |_| InstructionError::AccountBorrowFailed;
+        *info.try_borrow_mut_lamports().map_err(borrow_failed)? = account.get_lamports();
+        // SAFETY: no borrow of the account data is live.
+        unsafe { info.assign(account.get_owner().as_array()) };
+        info.realloc(account.get_data().len(), false)
+            .map_err(|_| InstructionError::InvalidRealloc)?;
+        info.try_borrow_mut_data()
+            .map_err(borrow_failed)?
+            .copy_from_slice(account.get_data());
+    }
+    Ok(())
+}
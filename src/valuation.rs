@@ -0,0 +1,179 @@
+//! Underlying token amounts of locked liquidity.
+//!
+//! CLMM prices are Q64.64 square roots of `token_1 / token_0`, computed
+//! exactly as the Raydium CLMM program does, so amounts match what removing
+//! the liquidity would return. Like [`crate::math`], helpers return `None` on
+//! overflow or out-of-range inputs.
+
+use crate::{
+    math::{cp_lp_to_token_amounts, mul_div_floor, CpPoolAmounts, U256},
+    raydium::clmm::{PersonalPositionState, PoolState},
+    states::LockedCpLiquidityState,
+};
+
+/// Lowest tick of a CLMM position.
+pub const MIN_TICK: i32 = -443_636;
+/// Highest tick of a CLMM position.
+pub const MAX_TICK: i32 = -MIN_TICK;
+
+/// Square root price at `MIN_TICK`.
+pub const MIN_SQRT_PRICE_X64: u128 = 4_295_048_016;
+/// Square root price at `MAX_TICK`.
+pub const MAX_SQRT_PRICE_X64: u128 = 79_226_673_521_066_979_257_578_248_091;
+
+// `2^64 / 1.0001^(2^(i - 1))` for each bit `i` of the absolute tick
+const TICK_FACTORS: [u128; 19] = [
+    0xfffcb933bd6fb800,
+    0xfff97272373d4000,
+    0xfff2e50f5f657000,
+    0xffe5caca7e10f000,
+    0xffcb9843d60f7000,
+    0xff973b41fa98e800,
+    0xff2ea16466c9b000,
+    0xfe5dee046a9a3800,
+    0xfcbe86c7900bb000,
+    0xf987a7253ac65800,
+    0xf3392b0822bb6000,
+    0xe7159475a2caf000,
+    0xd097f3bdfd2f2000,
+    0xa9f746462d9f8000,
+    0x70d869a156f31c00,
+    0x31be135f97ed3200,
+    0x9aa508b5b85a500,
+    0x5d6af8dedc582c,
+    0x2216e584f5fa,
+];
+
+/// `sqrt(1.0001^tick)` as a Q64.64 number, or `None` outside
+/// `MIN_TICK..=MAX_TICK`.
+pub fn sqrt_price_x64_at_tick(tick: i32) -> Option<u128> {
+    let abs_tick = tick.unsigned_abs();
+    if abs_tick > MAX_TICK as u32 {
+        return None;
+    }
+
+    // Factors are below 2^64 and the ratio at most 2^64, so products fit
+    let mut ratio = if abs_tick & 1 != 0 {
+        TICK_FACTORS[0]
+    } else {
+        1 << 64
+    };
+    for (bit, factor) in TICK_FACTORS.iter().enumerate().skip(1) {
+        if abs_tick & (1 << bit) != 0 {
+            ratio = (ratio * factor) >> 64;
+        }
+    }
+
+    if tick > 0 {
+        ratio = u128::MAX / ratio;
+    }
+    Some(ratio)
+}
+
+/// Token 0 held by `liquidity` between two square root prices, rounded down:
+/// `L * (sqrt_b - sqrt_a) / (sqrt_a * sqrt_b)`.
+pub fn amount_0_for_liquidity(
+    sqrt_price_a_x64: u128,
+    sqrt_price_b_x64: u128,
+    liquidity: u128,
+) -> Option<u64> {
+    let (lower, upper) = ordered(sqrt_price_a_x64, sqrt_price_b_x64);
+    if lower == 0 {
+        return None;
+    }
+
+    // floor((L << 64) * (upper - lower) / upper), split so every step fits:
+    // L * diff / upper < L, and the remainder term is below 2^64.
+    let (quotient, remainder) = U256::mul(liquidity, upper - lower).div_rem(upper)?;
+    let (fraction, _) = U256 {
+        hi: remainder >> 64,
+        lo: remainder << 64,
+    }
+    .div_rem(upper)?;
+    let scaled = U256 {
+        hi: quotient >> 64,
+        lo: (quotient << 64) | fraction,
+    };
+
+    let (amount, _) = scaled.div_rem(lower)?;
+    u64::try_from(amount).ok()
+}
+
+/// Token 1 held by `liquidity` between two square root prices, rounded down:
+/// `L * (sqrt_b - sqrt_a)`.
+pub fn amount_1_for_liquidity(
+    sqrt_price_a_x64: u128,
+    sqrt_price_b_x64: u128,
+    liquidity: u128,
+) -> Option<u64> {
+    let (lower, upper) = ordered(sqrt_price_a_x64, sqrt_price_b_x64);
+    let amount = mul_div_floor(liquidity, upper - lower, 1 << 64)?;
+    u64::try_from(amount).ok()
+}
+
+/// Token amounts of `liquidity` in `[tick_lower, tick_upper)` at the pool's
+/// current tick and square root price, rounded down as when it is removed.
+///
+/// Below the range the liquidity is all token 0, above it all token 1.
+pub fn amounts_for_liquidity(
+    tick_current: i32,
+    sqrt_price_x64: u128,
+    tick_lower: i32,
+    tick_upper: i32,
+    liquidity: u128,
+) -> Option<(u64, u64)> {
+    if tick_lower >= tick_upper {
+        return None;
+    }
+    let sqrt_lower = sqrt_price_x64_at_tick(tick_lower)?;
+    let sqrt_upper = sqrt_price_x64_at_tick(tick_upper)?;
+
+    if tick_current < tick_lower {
+        Some((
+            amount_0_for_liquidity(sqrt_lower, sqrt_upper, liquidity)?,
+            0,
+        ))
+    } else if tick_current < tick_upper {
+        Some((
+            amount_0_for_liquidity(sqrt_price_x64, sqrt_upper, liquidity)?,
+            amount_1_for_liquidity(sqrt_lower, sqrt_price_x64, liquidity)?,
+        ))
+    } else {
+        Some((
+            0,
+            amount_1_for_liquidity(sqrt_lower, sqrt_upper, liquidity)?,
+        ))
+    }
+}
+
+/// Token amounts of a CLMM position at the pool's current price, excluding
+/// uncollected fees and rewards.
+pub fn clmm_position_amounts(
+    pool: &PoolState,
+    position: &PersonalPositionState,
+) -> Option<(u64, u64)> {
+    amounts_for_liquidity(
+        pool.tick_current(),
+        pool.sqrt_price_x64(),
+        position.tick_lower_index(),
+        position.tick_upper_index(),
+        position.liquidity(),
+    )
+}
+
+/// Token amounts of the LP locked in `state`, rounded down as when burned.
+pub fn cp_locked_amounts(
+    state: &LockedCpLiquidityState,
+    pool: &CpPoolAmounts,
+) -> Option<(u64, u64)> {
+    cp_lp_to_token_amounts(state.locked_lp_amount(), pool)
+}
+
+#[inline(always)]
+fn ordered(a: u128, b: u128) -> (u128, u128) {
+    if a > b {
+        (b, a)
+    } else {
+        (a, b)
+    }
+}
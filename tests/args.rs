@@ -0,0 +1,68 @@
+use pinocchio_raydium_locking_program::{
+    client,
+    error::LockingError,
+    instructions::{CollectCpFeeArgs, LockClmmPositionArgs, LockCpLiquidityArgs},
+};
+use proptest::prelude::*;
+
+#[test]
+fn encodes_in_borsh_field_order() {
+    let args = LockCpLiquidityArgs {
+        lp_amount: 0x0102_0304_0506_0708,
+        with_metadata: true,
+    };
+    let mut out = [0xffu8; 12];
+
+    assert_eq!(args.encode_into(&mut out), Some(LockCpLiquidityArgs::LEN));
+    assert_eq!(out, [8, 7, 6, 5, 4, 3, 2, 1, 1, 0xff, 0xff, 0xff]);
+}
+
+#[test]
+fn encode_into_rejects_short_buffer() {
+    let mut out = [0u8; 8];
+    assert_eq!(LockCpLiquidityArgs::default().encode_into(&mut out), None);
+    assert_eq!(CollectCpFeeArgs::default().encode_into(&mut out), Some(8));
+}
+
+#[test]
+fn decode_rejects_invalid_data() {
+    assert_eq!(
+        CollectCpFeeArgs::decode(&[0; 7]),
+        Err(LockingError::InvalidInstructionData)
+    );
+    // Borsh booleans are 0 or 1
+    assert_eq!(
+        LockClmmPositionArgs::decode(&[2]),
+        Err(LockingError::InvalidInstructionData)
+    );
+    assert_eq!(
+        LockClmmPositionArgs::decode(&[1, 0xaa]),
+        Ok(LockClmmPositionArgs {
+            with_metadata: true
+        })
+    );
+}
+
+proptest! {
+    #[test]
+    fn lock_cp_liquidity_args_round_trip(lp_amount: u64, with_metadata: bool) {
+        let args = LockCpLiquidityArgs { lp_amount, with_metadata };
+        let mut out = [0u8; LockCpLiquidityArgs::LEN];
+        args.encode_into(&mut out);
+        prop_assert_eq!(LockCpLiquidityArgs::decode(&out), Ok(args));
+
+        // Client instruction data is the discriminator followed by the same bytes
+        let instruction = client::LockCpLiquidity { lp_amount, with_metadata, ..Default::default() }
+            .instruction();
+        prop_assert_eq!(&instruction.data[8..], &out[..]);
+    }
+
+    #[test]
+    fn collect_cp_fee_args_round_trip(fee_lp_amount: u64) {
+        let args = CollectCpFeeArgs { fee_lp_amount };
+        let mut out = [0u8; CollectCpFeeArgs::LEN];
+        args.encode_into(&mut out);
+        prop_assert_eq!(out, fee_lp_amount.to_le_bytes());
+        prop_assert_eq!(CollectCpFeeArgs::decode(&out), Ok(args));
+    }
+}
@@ -0,0 +1,151 @@
+use pinocchio::pubkey::Pubkey;
+use pinocchio_raydium_locking_program::{
+    client::{self, Instruction},
+    instructions::{self, AccountRole},
+    pda,
+    programs::{CPMM_PROGRAM_ID, TOKEN_PROGRAM_ID},
+    raydium::cpmm::PoolState,
+    states::LockedCpLiquidityState,
+    test_utils::{infos, TestAccount},
+};
+
+const POOL_STATE: Pubkey = [1; 32];
+const TOKEN_0_VAULT: Pubkey = [2; 32];
+const TOKEN_1_VAULT: Pubkey = [3; 32];
+const LP_MINT: Pubkey = [4; 32];
+const TOKEN_0_MINT: Pubkey = [5; 32];
+const TOKEN_1_MINT: Pubkey = [6; 32];
+const OWNER: Pubkey = [7; 32];
+const FEE_NFT_MINT: Pubkey = [8; 32];
+
+// CPMM `PoolState` data with the vaults and mints set
+fn cpmm_pool() -> Vec<u8> {
+    let mut data = vec![0u8; PoolState::LEN];
+    for (offset, key) in [
+        (PoolState::TOKEN_0_VAULT_OFFSET, TOKEN_0_VAULT),
+        (PoolState::TOKEN_1_VAULT_OFFSET, TOKEN_1_VAULT),
+        (PoolState::LP_MINT_OFFSET, LP_MINT),
+        (PoolState::TOKEN_0_MINT_OFFSET, TOKEN_0_MINT),
+        (PoolState::TOKEN_1_MINT_OFFSET, TOKEN_1_MINT),
+    ] {
+        data[offset..offset + 32].copy_from_slice(&key);
+    }
+    data
+}
+
+// One account per instruction account, with the flags of its role
+fn accounts(roles: &[AccountRole], instruction: &Instruction) -> Vec<TestAccount> {
+    roles
+        .iter()
+        .zip(&instruction.accounts)
+        .map(|(role, meta)| TestAccount::for_role(meta.pubkey, role))
+        .collect()
+}
+
+#[test]
+fn lock_cp_liquidity_is_filled_from_the_pool() {
+    let pool = cpmm_pool();
+    let pool = unsafe { PoolState::from_bytes(&pool[8..]) };
+    let lock =
+        client::LockCpLiquidity::new(POOL_STATE, pool, OWNER, OWNER, OWNER, FEE_NFT_MINT, 10);
+
+    assert_eq!(lock.pool_state, POOL_STATE);
+    assert_eq!(lock.lp_mint, LP_MINT);
+    assert_eq!(lock.token_0_vault, TOKEN_0_VAULT);
+    assert_eq!(lock.token_1_vault, TOKEN_1_VAULT);
+    assert_eq!(
+        lock.liquidity_owner_lp,
+        pda::find_associated_token_account(&OWNER, &LP_MINT, &TOKEN_PROGRAM_ID).0
+    );
+    assert_eq!(
+        lock.locked_liquidity,
+        pda::find_locked_liquidity(&FEE_NFT_MINT).0
+    );
+    assert_eq!((lock.lp_amount, lock.with_metadata), (10, false));
+
+    let instruction = lock.instruction();
+    let accounts = accounts(&instructions::LockCpLiquidity::ACCOUNTS, &instruction);
+    let infos = infos(&accounts);
+    let (lock, _) = instructions::LockCpLiquidity::from_accounts(&infos, 10, false).unwrap();
+    assert_eq!(lock.validate(), Ok(()));
+}
+
+#[test]
+fn collect_cp_fee_is_filled_from_the_locked_liquidity_and_pool() {
+    let pool = cpmm_pool();
+    let pool = unsafe { PoolState::from_bytes(&pool[8..]) };
+    let locked = LockedCpLiquidityState::builder()
+        .pool_id(POOL_STATE)
+        .fee_nft_mint(FEE_NFT_MINT)
+        .locked_lp_mint(LP_MINT)
+        .build();
+    let collect = client::CollectCpFee::new(&locked, pool, OWNER, [9; 32], [10; 32], 5);
+
+    assert_eq!(collect.pool_state, POOL_STATE);
+    assert_eq!(collect.cpmm_program, CPMM_PROGRAM_ID);
+    assert_eq!(collect.lp_mint, LP_MINT);
+    assert_eq!(collect.token_0_vault, TOKEN_0_VAULT);
+    assert_eq!(collect.token_1_vault, TOKEN_1_VAULT);
+    assert_eq!(collect.vault_0_mint, TOKEN_0_MINT);
+    assert_eq!(collect.vault_1_mint, TOKEN_1_MINT);
+    assert_eq!(
+        collect.locked_liquidity,
+        pda::find_locked_liquidity(&FEE_NFT_MINT).0
+    );
+    assert_eq!(
+        (
+            collect.recipient_token_0_account,
+            collect.recipient_token_1_account
+        ),
+        ([9; 32], [10; 32])
+    );
+    assert_eq!(collect.fee_lp_amount, 5);
+
+    let instruction = collect.instruction();
+    let accounts = accounts(&instructions::CollectCpFee::ACCOUNTS, &instruction);
+    let infos = infos(&accounts);
+    let (collect, _) = instructions::CollectCpFee::from_accounts(&infos, 5).unwrap();
+    assert_eq!(collect.validate(), Ok(()));
+}
+
+// Gives every account of `builder` a distinct key through its `ACCOUNTS` name
+// and checks the instruction lists them in table order with the table's flags
+macro_rules! check_key_order {
+    ($name:ident) => {{
+        let mut builder = client::$name::default();
+        for (i, role) in instructions::$name::ACCOUNTS.iter().enumerate() {
+            let key = builder
+                .key_mut(role.name)
+                .unwrap_or_else(|| panic!("no `{}` key", role.name));
+            *key = [i as u8 + 1; 32];
+        }
+        assert_eq!(builder.key("not_an_account"), None);
+
+        let instruction = builder.instruction();
+        assert_eq!(
+            instruction.accounts.len(),
+            instructions::$name::ACCOUNTS.len()
+        );
+        for (i, (meta, role)) in instruction
+            .accounts
+            .iter()
+            .zip(&instructions::$name::ACCOUNTS)
+            .enumerate()
+        {
+            assert_eq!(meta.pubkey, [i as u8 + 1; 32], "{}", role.name);
+            assert_eq!(Some(&meta.pubkey), builder.key(role.name));
+            assert_eq!(
+                (meta.is_signer, meta.is_writable),
+                (role.is_signer, role.is_writable)
+            );
+        }
+    }};
+}
+
+#[test]
+fn client_keys_follow_the_accounts_table() {
+    check_key_order!(LockCpLiquidity);
+    check_key_order!(LockClmmPosition);
+    check_key_order!(CollectCpFee);
+    check_key_order!(CollectClmmFeeAndReward);
+}
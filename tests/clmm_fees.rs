@@ -0,0 +1,365 @@
+mod common;
+
+use core::mem::size_of;
+
+use common::put;
+use pinocchio_raydium_locking_program::{
+    math::{clmm_fee_growth_inside, clmm_reward_growths_global, preview_clmm_fee, ClmmFeePreview},
+    raydium::clmm::{
+        PersonalPositionState, PoolState, PositionRewardInfo, RewardInfo, TickArrayState, TickState,
+    },
+};
+
+const Q64: u128 = 1 << 64;
+const POOL_ID: [u8; 32] = [7; 32];
+const TICK_SPACING: u16 = 10;
+const TICK_LOWER: i32 = -100;
+const TICK_UPPER: i32 = 100;
+const LIQUIDITY: u128 = 1_000_000;
+
+struct Reward {
+    open_time: u64,
+    end_time: u64,
+    last_update_time: u64,
+    emissions_per_second_x64: u128,
+    growth_global_x64: u128,
+}
+
+fn pool(
+    tick_current: i32,
+    fee_growth_global_x64: [u128; 2],
+    rewards: [Option<Reward>; 3],
+) -> Vec<u8> {
+    let mut data = vec![0u8; PoolState::LEN];
+    put(
+        &mut data,
+        PoolState::TICK_SPACING_OFFSET,
+        &TICK_SPACING.to_le_bytes(),
+    );
+    put(
+        &mut data,
+        PoolState::LIQUIDITY_OFFSET,
+        &(2 * LIQUIDITY).to_le_bytes(),
+    );
+    put(
+        &mut data,
+        PoolState::TICK_CURRENT_OFFSET,
+        &tick_current.to_le_bytes(),
+    );
+    put(
+        &mut data,
+        PoolState::FEE_GROWTH_GLOBAL_0_X64_OFFSET,
+        &fee_growth_global_x64[0].to_le_bytes(),
+    );
+    put(
+        &mut data,
+        PoolState::FEE_GROWTH_GLOBAL_1_X64_OFFSET,
+        &fee_growth_global_x64[1].to_le_bytes(),
+    );
+    for (i, reward) in rewards.iter().enumerate() {
+        let Some(reward) = reward else { continue };
+        let offset = PoolState::REWARD_INFOS_OFFSET + size_of::<RewardInfo>() * i;
+        // Opening
+        data[offset + RewardInfo::REWARD_STATE_OFFSET] = 2;
+        put(
+            &mut data,
+            offset + RewardInfo::OPEN_TIME_OFFSET,
+            &reward.open_time.to_le_bytes(),
+        );
+        put(
+            &mut data,
+            offset + RewardInfo::END_TIME_OFFSET,
+            &reward.end_time.to_le_bytes(),
+        );
+        put(
+            &mut data,
+            offset + RewardInfo::LAST_UPDATE_TIME_OFFSET,
+            &reward.last_update_time.to_le_bytes(),
+        );
+        put(
+            &mut data,
+            offset + RewardInfo::EMISSIONS_PER_SECOND_X64_OFFSET,
+            &reward.emissions_per_second_x64.to_le_bytes(),
+        );
+        put(
+            &mut data,
+            offset + RewardInfo::REWARD_GROWTH_GLOBAL_X64_OFFSET,
+            &reward.growth_global_x64.to_le_bytes(),
+        );
+    }
+    data
+}
+
+fn position(
+    liquidity: u128,
+    fee_growth_inside_last_x64: [u128; 2],
+    token_fees_owed: [u64; 2],
+    rewards: [(u128, u64); 3],
+) -> Vec<u8> {
+    let mut data = vec![0u8; PersonalPositionState::LEN];
+    put(&mut data, PersonalPositionState::POOL_ID_OFFSET, &POOL_ID);
+    put(
+        &mut data,
+        PersonalPositionState::TICK_LOWER_INDEX_OFFSET,
+        &TICK_LOWER.to_le_bytes(),
+    );
+    put(
+        &mut data,
+        PersonalPositionState::TICK_UPPER_INDEX_OFFSET,
+        &TICK_UPPER.to_le_bytes(),
+    );
+    put(
+        &mut data,
+        PersonalPositionState::LIQUIDITY_OFFSET,
+        &liquidity.to_le_bytes(),
+    );
+    put(
+        &mut data,
+        PersonalPositionState::FEE_GROWTH_INSIDE_0_LAST_X64_OFFSET,
+        &fee_growth_inside_last_x64[0].to_le_bytes(),
+    );
+    put(
+        &mut data,
+        PersonalPositionState::FEE_GROWTH_INSIDE_1_LAST_X64_OFFSET,
+        &fee_growth_inside_last_x64[1].to_le_bytes(),
+    );
+    put(
+        &mut data,
+        PersonalPositionState::TOKEN_FEES_OWED_0_OFFSET,
+        &token_fees_owed[0].to_le_bytes(),
+    );
+    put(
+        &mut data,
+        PersonalPositionState::TOKEN_FEES_OWED_1_OFFSET,
+        &token_fees_owed[1].to_le_bytes(),
+    );
+    for (i, (growth_inside_last_x64, reward_amount_owed)) in rewards.iter().enumerate() {
+        let offset =
+            PersonalPositionState::REWARD_INFOS_OFFSET + size_of::<PositionRewardInfo>() * i;
+        put(
+            &mut data,
+            offset + PositionRewardInfo::GROWTH_INSIDE_LAST_X64_OFFSET,
+            &growth_inside_last_x64.to_le_bytes(),
+        );
+        put(
+            &mut data,
+            offset + PositionRewardInfo::REWARD_AMOUNT_OWED_OFFSET,
+            &reward_amount_owed.to_le_bytes(),
+        );
+    }
+    data
+}
+
+// One tick array holding `tick` with the given growths outside it
+fn tick_array(
+    start_tick_index: i32,
+    tick: i32,
+    fee_outside: [u128; 2],
+    reward_outside: [u128; 3],
+) -> Vec<u8> {
+    let mut data = vec![0u8; TickArrayState::LEN];
+    put(&mut data, TickArrayState::POOL_ID_OFFSET, &POOL_ID);
+    put(
+        &mut data,
+        TickArrayState::START_TICK_INDEX_OFFSET,
+        &start_tick_index.to_le_bytes(),
+    );
+    let index = ((tick - start_tick_index) / TICK_SPACING as i32) as usize;
+    let offset = TickArrayState::TICKS_OFFSET + size_of::<TickState>() * index;
+    put(
+        &mut data,
+        offset + TickState::TICK_OFFSET,
+        &tick.to_le_bytes(),
+    );
+    put(
+        &mut data,
+        offset + TickState::LIQUIDITY_GROSS_OFFSET,
+        &LIQUIDITY.to_le_bytes(),
+    );
+    put(
+        &mut data,
+        offset + TickState::FEE_GROWTH_OUTSIDE_0_X64_OFFSET,
+        &fee_outside[0].to_le_bytes(),
+    );
+    put(
+        &mut data,
+        offset + TickState::FEE_GROWTH_OUTSIDE_1_X64_OFFSET,
+        &fee_outside[1].to_le_bytes(),
+    );
+    for (i, growth) in reward_outside.iter().enumerate() {
+        put(
+            &mut data,
+            offset + TickState::REWARD_GROWTHS_OUTSIDE_X64_OFFSET + 16 * i,
+            &growth.to_le_bytes(),
+        );
+    }
+    data
+}
+
+fn tick_arrays(fee_outside: [[u128; 2]; 2], reward_outside: [[u128; 3]; 2]) -> (Vec<u8>, Vec<u8>) {
+    (
+        tick_array(-600, TICK_LOWER, fee_outside[0], reward_outside[0]),
+        tick_array(0, TICK_UPPER, fee_outside[1], reward_outside[1]),
+    )
+}
+
+fn preview(
+    pool: &[u8],
+    position: &[u8],
+    arrays: &(Vec<u8>, Vec<u8>),
+    now: u64,
+) -> Option<ClmmFeePreview> {
+    unsafe {
+        preview_clmm_fee(
+            PoolState::from_bytes(&pool[8..]),
+            PersonalPositionState::from_bytes(&position[8..]),
+            TickArrayState::from_bytes(&arrays.0[8..]),
+            TickArrayState::from_bytes(&arrays.1[8..]),
+            now,
+        )
+    }
+}
+
+#[test]
+fn fee_growth_inside_follows_the_current_tick() {
+    let arrays = tick_arrays([[2 * Q64, 0], [3 * Q64, 0]], [[0; 3]; 2]);
+    let lower = unsafe { TickArrayState::from_bytes(&arrays.0[8..]) }
+        .tick(TICK_LOWER, TICK_SPACING)
+        .unwrap();
+    let upper = unsafe { TickArrayState::from_bytes(&arrays.1[8..]) }
+        .tick(TICK_UPPER, TICK_SPACING)
+        .unwrap();
+    let growth_inside = |tick_current| {
+        let pool = pool(tick_current, [10 * Q64, 0], [None, None, None]);
+        clmm_fee_growth_inside(unsafe { PoolState::from_bytes(&pool[8..]) }, lower, upper)
+    };
+
+    // In range: global minus outside on both sides
+    assert_eq!(growth_inside(0), Some((5 * Q64, 0)));
+    // Out of range the outside growths of both ticks lie on the same side,
+    // so the difference can wrap
+    assert_eq!(growth_inside(-200), Some((Q64.wrapping_neg(), 0)));
+    assert_eq!(growth_inside(100), Some((Q64, 0)));
+}
+
+#[test]
+fn preview_adds_fee_and_reward_growth_to_amounts_owed() {
+    let pool = pool(
+        0,
+        [10 * Q64, Q64],
+        [
+            Some(Reward {
+                open_time: 100,
+                end_time: 1_000,
+                last_update_time: 200,
+                emissions_per_second_x64: 2 * Q64,
+                growth_global_x64: 0,
+            }),
+            None,
+            Some(Reward {
+                open_time: 100,
+                end_time: 250,
+                last_update_time: 250,
+                emissions_per_second_x64: 2 * Q64,
+                growth_global_x64: Q64,
+            }),
+        ],
+    );
+    // Token 1 growth inside wraps below zero, as it can on chain
+    let arrays = tick_arrays(
+        [[2 * Q64, 3 * Q64], [3 * Q64, 0]],
+        [[0; 3], [0, 0, Q64 / 4]],
+    );
+    let position = position(
+        LIQUIDITY,
+        [Q64, (3 * Q64).wrapping_neg()],
+        [7, 0],
+        [(0, 5), (0, 9), (0, 0)],
+    );
+
+    // 100s of emissions at 2 tokens/s spread over the pool's 2_000_000
+    // liquidity, of which the position holds half
+    assert_eq!(
+        preview(&pool, &position, &arrays, 300),
+        Some(ClmmFeePreview {
+            fee_amount_0: 4_000_007,
+            fee_amount_1: 1_000_000,
+            reward_amounts: [104, 9, 750_000],
+        })
+    );
+    // Halfway through that period, once reward 2 has ended
+    let earlier = preview(&pool, &position, &arrays, 250).unwrap();
+    assert_eq!(earlier.reward_amounts, [54, 9, 750_000]);
+    // The clock never runs behind a reward's last update
+    assert_eq!(preview(&pool, &position, &arrays, 200), None);
+}
+
+#[test]
+fn reward_growth_stops_at_end_time_and_without_liquidity() {
+    let reward = || Reward {
+        open_time: 100,
+        end_time: 1_000,
+        last_update_time: 100,
+        emissions_per_second_x64: Q64,
+        growth_global_x64: 5,
+    };
+    let data = pool(0, [0; 2], [Some(reward()), None, None]);
+    let pool_state = unsafe { PoolState::from_bytes(&data[8..]) };
+    let full_period = 5 + 900 * Q64 / (2 * LIQUIDITY);
+    assert_eq!(clmm_reward_growths_global(pool_state, 100), Some([5, 0, 0]));
+    assert_eq!(
+        clmm_reward_growths_global(pool_state, 1_000),
+        Some([full_period, 0, 0])
+    );
+    assert_eq!(
+        clmm_reward_growths_global(pool_state, 5_000),
+        Some([full_period, 0, 0])
+    );
+
+    let mut data = data;
+    put(&mut data, PoolState::LIQUIDITY_OFFSET, &0u128.to_le_bytes());
+    let pool_state = unsafe { PoolState::from_bytes(&data[8..]) };
+    assert_eq!(
+        clmm_reward_growths_global(pool_state, 5_000),
+        Some([5, 0, 0])
+    );
+}
+
+#[test]
+fn fee_delta_of_u64_max_or_more_is_dropped() {
+    let pool = pool(0, [Q64, 2 * Q64], [None, None, None]);
+    let arrays = tick_arrays([[0; 2]; 2], [[0; 3]; 2]);
+    let position = position(u64::MAX as u128, [0, Q64], [3, 4], [(0, 0); 3]);
+    assert_eq!(
+        preview(&pool, &position, &arrays, 0),
+        Some(ClmmFeePreview {
+            fee_amount_0: 3,
+            fee_amount_1: 4,
+            reward_amounts: [0; 3],
+        })
+    );
+}
+
+#[test]
+fn preview_rejects_states_the_collect_would_fail_on() {
+    let pool_data = pool(0, [Q64, 0], [None, None, None]);
+    let position_data = position(LIQUIDITY, [0; 2], [u64::MAX, 0], [(0, 0); 3]);
+    let arrays = tick_arrays([[0; 2]; 2], [[0; 3]; 2]);
+    // Fees owed would overflow
+    assert_eq!(preview(&pool_data, &position_data, &arrays, 0), None);
+
+    let position_data = position(LIQUIDITY, [0; 2], [0; 2], [(0, 0); 3]);
+    // Tick arrays swapped, so neither holds the tick it is read for
+    let swapped = (arrays.1.clone(), arrays.0.clone());
+    assert_eq!(preview(&pool_data, &position_data, &swapped, 0), None);
+
+    // Tick arrays of another pool
+    let mut other_pool = arrays.clone();
+    put(&mut other_pool.0, 8, &[8; 32]);
+    assert_eq!(preview(&pool_data, &position_data, &other_pool, 0), None);
+
+    // Outside growth above the global growth below the price
+    let below = pool(-200, [Q64, 0], [None, None, None]);
+    let arrays = tick_arrays([[2 * Q64, 0], [0; 2]], [[0; 3]; 2]);
+    assert_eq!(preview(&below, &position_data, &arrays, 0), None);
+}
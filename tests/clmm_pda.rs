@@ -0,0 +1,32 @@
+use pinocchio_raydium_locking_program::{
+    pda::{find_clmm_position_pdas, find_protocol_position, find_tick_array},
+    raydium::clmm::tick_array_start_index,
+};
+
+#[test]
+fn tick_array_start_index_rounds_down() {
+    assert_eq!(tick_array_start_index(0, 1), Some(0));
+    assert_eq!(tick_array_start_index(59, 1), Some(0));
+    assert_eq!(tick_array_start_index(60, 1), Some(60));
+    assert_eq!(tick_array_start_index(-1, 1), Some(-60));
+    assert_eq!(tick_array_start_index(-60, 1), Some(-60));
+    assert_eq!(tick_array_start_index(-61, 1), Some(-120));
+    assert_eq!(tick_array_start_index(-10, 10), Some(-600));
+    assert_eq!(tick_array_start_index(1_199, 20), Some(0));
+    assert_eq!(tick_array_start_index(-443_636, 60), Some(-446_400));
+    assert_eq!(tick_array_start_index(100, 0), None);
+}
+
+#[test]
+fn position_pdas_use_array_starts_and_exact_ticks() {
+    let pool_state = [7u8; 32];
+    let pdas = find_clmm_position_pdas(&pool_state, -130, 250, 10).unwrap();
+
+    assert_eq!(pdas.tick_array_lower, find_tick_array(&pool_state, -600).0);
+    assert_eq!(pdas.tick_array_upper, find_tick_array(&pool_state, 0).0);
+    assert_eq!(
+        pdas.protocol_position,
+        find_protocol_position(&pool_state, -130, 250).0
+    );
+    assert_eq!(find_clmm_position_pdas(&pool_state, -130, 250, 0), None);
+}
@@ -0,0 +1,5 @@
+/// Writes `bytes` into `data` at `offset`, for account fixtures laid out by
+/// hand from the documented offsets.
+pub fn put(data: &mut [u8], offset: usize, bytes: &[u8]) {
+    data[offset..offset + bytes.len()].copy_from_slice(bytes);
+}
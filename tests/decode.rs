@@ -0,0 +1,159 @@
+use pinocchio_raydium_locking_program::{
+    client::{self, AccountMeta, Instruction},
+    decode::{decode_instruction, DecodedInstruction, LockingInstruction},
+    error::LockingError,
+    instructions::{CollectClmmFeeAndReward, LockCpLiquidity, MAX_REMAINING_ACCOUNTS},
+};
+
+fn lock_cp() -> Instruction {
+    client::LockCpLiquidity {
+        pool_state: [1; 32],
+        lp_mint: [2; 32],
+        fee_nft_mint: [3; 32],
+        liquidity_owner: [4; 32],
+        lp_amount: 1_000,
+        with_metadata: true,
+        ..Default::default()
+    }
+    .instruction()
+}
+
+fn collect_clmm(remaining: usize) -> Instruction {
+    client::CollectClmmFeeAndReward {
+        pool_state: [1; 32],
+        vault_1_mint: [2; 32],
+        remaining_accounts: (0..remaining)
+            .map(|i| AccountMeta {
+                pubkey: [i as u8 + 100; 32],
+                is_signer: false,
+                is_writable: i % 3 != 2,
+            })
+            .collect(),
+        ..Default::default()
+    }
+    .instruction()
+}
+
+#[test]
+fn decodes_client_instructions() {
+    let instruction = lock_cp();
+    let decoded = decode_instruction(&instruction.data, &instruction.accounts).unwrap();
+
+    assert_eq!(
+        decoded.instruction,
+        LockingInstruction::LockCpLiquidity {
+            lp_amount: 1_000,
+            with_metadata: true
+        }
+    );
+    let accounts = decoded.lock_cp_liquidity().unwrap();
+    assert_eq!(accounts.pool_state().pubkey, [1; 32]);
+    assert_eq!(accounts.lp_mint().pubkey, [2; 32]);
+    assert_eq!(accounts.fee_nft_mint().pubkey, [3; 32]);
+    assert_eq!(accounts.liquidity_owner().pubkey, [4; 32]);
+    assert!(decoded.lock_clmm_position().is_none());
+    assert!(decoded.collect_cp_fee().is_none());
+    assert!(decoded.remaining_accounts().is_empty());
+
+    let names: Vec<_> = decoded
+        .named_accounts()
+        .map(|(role, _)| role.name)
+        .collect();
+    let expected: Vec<_> = LockCpLiquidity::ACCOUNTS
+        .iter()
+        .map(|role| role.name)
+        .collect();
+    assert_eq!(names, expected);
+    for (role, meta) in decoded.named_accounts() {
+        assert_eq!(
+            (role.is_signer, role.is_writable),
+            (meta.is_signer, meta.is_writable)
+        );
+    }
+}
+
+#[test]
+fn decodes_remaining_accounts() {
+    let instruction = collect_clmm(MAX_REMAINING_ACCOUNTS);
+    let decoded = decode_instruction(&instruction.data, &instruction.accounts).unwrap();
+
+    assert_eq!(
+        decoded.instruction,
+        LockingInstruction::CollectClmmFeeAndReward
+    );
+    let accounts = decoded.collect_clmm_fee_and_reward().unwrap();
+    assert_eq!(accounts.pool_state().pubkey, [1; 32]);
+    assert_eq!(accounts.vault_1_mint().pubkey, [2; 32]);
+    assert_eq!(
+        decoded.remaining_accounts(),
+        &instruction.accounts[CollectClmmFeeAndReward::ACCOUNTS.len()..]
+    );
+    assert_eq!(
+        decoded.named_accounts().count(),
+        CollectClmmFeeAndReward::ACCOUNTS.len()
+    );
+}
+
+#[test]
+fn rejects_short_or_unknown_data() {
+    let accounts = lock_cp().accounts;
+
+    for data in [&[][..], &LockCpLiquidity::DISCRIMINATOR[..7]] {
+        assert_eq!(
+            decode_instruction(data, &accounts).unwrap_err(),
+            LockingError::InvalidInstructionData
+        );
+    }
+    assert_eq!(
+        decode_instruction(&[0; 8], &accounts).unwrap_err(),
+        LockingError::UnknownInstructionDiscriminator
+    );
+}
+
+#[test]
+fn rejects_truncated_or_invalid_args() {
+    let instruction = lock_cp();
+
+    for len in 8..instruction.data.len() {
+        assert_eq!(
+            decode_instruction(&instruction.data[..len], &instruction.accounts).unwrap_err(),
+            LockingError::InvalidInstructionData
+        );
+    }
+
+    // Borsh booleans are 0 or 1
+    let mut data = instruction.data.clone();
+    *data.last_mut().unwrap() = 2;
+    assert_eq!(
+        decode_instruction(&data, &instruction.accounts).unwrap_err(),
+        LockingError::InvalidInstructionData
+    );
+}
+
+#[test]
+fn rejects_missing_accounts() {
+    let instruction = collect_clmm(0);
+    let accounts = &instruction.accounts[..instruction.accounts.len() - 1];
+    assert_eq!(
+        decode_instruction(&instruction.data, accounts).unwrap_err(),
+        LockingError::NotEnoughAccountKeys
+    );
+}
+
+// The typed views never index past the accounts, even for a hand-built
+// `DecodedInstruction`
+#[test]
+fn account_views_need_every_fixed_account() {
+    let instruction = lock_cp();
+    let decoded = decode_instruction(&instruction.data, &instruction.accounts).unwrap();
+    let short = DecodedInstruction {
+        instruction: decoded.instruction,
+        accounts: &instruction.accounts[..LockCpLiquidity::ACCOUNTS.len() - 1],
+    };
+    assert!(short.lock_cp_liquidity().is_none());
+
+    let accounts = decoded.lock_cp_liquidity().unwrap();
+    let last = LockCpLiquidity::ACCOUNTS.len() - 1;
+    assert_eq!(LockCpLiquidity::ACCOUNTS[last].name, "metadata_program");
+    assert_eq!(accounts.metadata_program(), &instruction.accounts[last]);
+}
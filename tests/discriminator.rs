@@ -0,0 +1,143 @@
+use pinocchio_raydium_locking_program::{
+    client,
+    discriminator::{anchor_discriminator, sha256},
+    instructions::{CollectClmmFeeAndReward, CollectCpFee, LockClmmPosition, LockCpLiquidity},
+    raydium::{clmm, cpmm},
+    states::{LockedClmmPositionState, LockedCpLiquidityState},
+};
+use proptest::prelude::*;
+use sha2::{Digest, Sha256};
+
+fn hex(bytes: &str) -> Vec<u8> {
+    (0..bytes.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&bytes[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn sha256_matches_known_vectors() {
+    let vectors = [
+        (
+            "",
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        ),
+        (
+            "abc",
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+        ),
+        (
+            "abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1",
+        ),
+    ];
+    for (input, digest) in vectors {
+        assert_eq!(
+            sha256(input.as_bytes()).to_vec(),
+            hex(digest),
+            "sha256({input:?})"
+        );
+    }
+}
+
+// Golden values, independent of the const derivation
+#[test]
+fn discriminators_match_golden_values() {
+    let golden: [(&str, [u8; 8], &str); 11] = [
+        (
+            "LockCpLiquidity",
+            LockCpLiquidity::DISCRIMINATOR,
+            "d89d1d4e26331f1a",
+        ),
+        (
+            "LockClmmPosition",
+            LockClmmPosition::DISCRIMINATOR,
+            "bc25b38352965449",
+        ),
+        (
+            "CollectCpFee",
+            CollectCpFee::DISCRIMINATOR,
+            "081e33c7d1b8f785",
+        ),
+        (
+            "CollectClmmFeeAndReward",
+            CollectClmmFeeAndReward::DISCRIMINATOR,
+            "1048fac60ea2d413",
+        ),
+        (
+            "LockedCpLiquidityState",
+            LockedCpLiquidityState::DISCRIMINATOR,
+            "190aeec5cfea4916",
+        ),
+        (
+            "LockedClmmPositionState",
+            LockedClmmPositionState::DISCRIMINATOR,
+            "34170507aa5a6cd5",
+        ),
+        (
+            "cpmm::PoolState",
+            cpmm::PoolState::DISCRIMINATOR,
+            "f7ede3f5d7c3de46",
+        ),
+        (
+            "cpmm::AmmConfig",
+            cpmm::AmmConfig::DISCRIMINATOR,
+            "daf42168cbcb2b6f",
+        ),
+        (
+            "clmm::PersonalPositionState",
+            clmm::PersonalPositionState::DISCRIMINATOR,
+            "466f967ee60f1975",
+        ),
+        (
+            "clmm::ProtocolPositionState",
+            clmm::ProtocolPositionState::DISCRIMINATOR,
+            "64e2916392daa06a",
+        ),
+        (
+            "clmm::TickArrayState",
+            clmm::TickArrayState::DISCRIMINATOR,
+            "c09b55cd31f9812a",
+        ),
+    ];
+    for (name, discriminator, expected) in golden {
+        assert_eq!(discriminator.to_vec(), hex(expected), "{name}");
+    }
+    assert_eq!(
+        clmm::PoolState::DISCRIMINATOR,
+        cpmm::PoolState::DISCRIMINATOR
+    );
+}
+
+#[test]
+fn encoded_instructions_start_with_derived_discriminator() {
+    let encoded = [
+        (
+            "lock_cp_liquidity",
+            client::LockCpLiquidity::default().instruction(),
+        ),
+        (
+            "lock_clmm_position",
+            client::LockClmmPosition::default().instruction(),
+        ),
+        (
+            "collect_cp_fees",
+            client::CollectCpFee::default().instruction(),
+        ),
+        (
+            "collect_clmm_fees_and_rewards",
+            client::CollectClmmFeeAndReward::default().instruction(),
+        ),
+    ];
+    for (name, instruction) in encoded {
+        let discriminator = anchor_discriminator(&format!("global:{name}"));
+        assert_eq!(instruction.data[..8], discriminator, "{name}");
+    }
+}
+
+proptest! {
+    #[test]
+    fn sha256_matches_sha2(data in proptest::collection::vec(any::<u8>(), 0..300)) {
+        prop_assert_eq!(sha256(&data).to_vec(), Sha256::digest(&data).to_vec());
+    }
+}
@@ -0,0 +1,180 @@
+use pinocchio::program_error::ProgramError;
+use pinocchio_raydium_locking_program::{
+    error::{
+        ErrorCode, LockProgramError, LockingError, ValidationError, ANCHOR_ERROR_CODE_OFFSET,
+        LOCKING_ERROR_CODE_OFFSET,
+    },
+    states::{LockAccount, LockedCpLiquidityState},
+    test_utils::TestAccount,
+    ID,
+};
+
+const LOCKING_ERRORS: [LockingError; 12] = [
+    LockingError::InvalidAccountDiscriminator,
+    LockingError::UnknownInstructionDiscriminator,
+    LockingError::InvalidInstructionData,
+    LockingError::NotEnoughAccountKeys,
+    LockingError::LiquidityNotLocked,
+    LockingError::AccountNotSigner,
+    LockingError::AccountNotWritable,
+    LockingError::IncorrectProgramId,
+    LockingError::InvalidPda,
+    LockingError::InvalidAccountOwner,
+    LockingError::InvalidAccountLength,
+    LockingError::TooManyAccounts,
+];
+
+#[test]
+fn locking_errors_round_trip_through_program_error() {
+    for (i, error) in LOCKING_ERRORS.into_iter().enumerate() {
+        let code = LOCKING_ERROR_CODE_OFFSET + i as u32;
+        assert_eq!(error.code(), code);
+        assert_eq!(ProgramError::from(error), ProgramError::Custom(code));
+        assert_eq!(LockingError::from_code(code), Some(error));
+        assert_eq!(LockingError::from_program_error(&error.into()), Some(error));
+        assert!(!error.message().is_empty());
+    }
+
+    let past_last = LOCKING_ERROR_CODE_OFFSET + LOCKING_ERRORS.len() as u32;
+    for code in [0, 1, 100, 6000, LOCKING_ERROR_CODE_OFFSET - 1, past_last] {
+        assert_eq!(LockingError::from_code(code), None);
+    }
+    assert_eq!(
+        LockingError::from_program_error(&ProgramError::InvalidAccountData),
+        None
+    );
+}
+
+#[test]
+fn locking_error_codes_stay_clear_of_anchor_codes() {
+    // Anchor framework codes are below 6000, program codes count up from it
+    assert!(LOCKING_ERROR_CODE_OFFSET > ANCHOR_ERROR_CODE_OFFSET + u16::MAX as u32);
+}
+
+#[test]
+fn validation_errors_map_to_locking_errors() {
+    for (error, expected) in [
+        (
+            ValidationError::NotSigner("payer"),
+            LockingError::AccountNotSigner,
+        ),
+        (
+            ValidationError::NotWritable("payer"),
+            LockingError::AccountNotWritable,
+        ),
+        (
+            ValidationError::IncorrectProgramId("rent"),
+            LockingError::IncorrectProgramId,
+        ),
+        (
+            ValidationError::InvalidPda("authority"),
+            LockingError::InvalidPda,
+        ),
+        (
+            ValidationError::TooManyAccounts("remaining_accounts"),
+            LockingError::TooManyAccounts,
+        ),
+    ] {
+        assert_eq!(ProgramError::from(error), expected.into());
+    }
+}
+
+#[test]
+fn anchor_errors_round_trip_through_their_codes() {
+    let mut decoded = 0;
+    for code in 0..ANCHOR_ERROR_CODE_OFFSET {
+        if let Some(error) = LockProgramError::from_code(code) {
+            assert_eq!(error.code(), code);
+            assert!(!error.message().is_empty());
+            assert_eq!(
+                LockProgramError::from_program_error(&ProgramError::Custom(code)),
+                Some(error)
+            );
+            decoded += 1;
+        }
+    }
+    assert_eq!(decoded, 55);
+
+    for (code, error) in [
+        (100, LockProgramError::InstructionMissing),
+        (2003, LockProgramError::ConstraintRaw),
+        (2006, LockProgramError::ConstraintSeeds),
+        (3007, LockProgramError::AccountOwnedByWrongProgram),
+        (3012, LockProgramError::AccountNotInitialized),
+        (4102, LockProgramError::InvalidNumericConversion),
+    ] {
+        assert_eq!(LockProgramError::from_code(code), Some(error));
+    }
+    for code in [0, 104, 2008, 3018, 4103] {
+        assert_eq!(LockProgramError::from_code(code), None);
+    }
+}
+
+#[test]
+fn idl_errors_decode_by_name() {
+    for error in ErrorCode::ALL {
+        let code = error.code();
+        assert!(code >= ANCHOR_ERROR_CODE_OFFSET);
+        let decoded = LockProgramError::from_program_error(&ProgramError::Custom(code)).unwrap();
+        assert_eq!(decoded, LockProgramError::Defined(error));
+        assert_eq!(decoded.code(), code);
+        assert_eq!(decoded.message(), error.message());
+        assert!(!error.message().is_empty());
+    }
+}
+
+#[test]
+fn program_errors_missing_from_the_idl_keep_their_index() {
+    let first_unnamed = ErrorCode::ALL
+        .iter()
+        .map(|error| error.code() + 1)
+        .max()
+        .unwrap_or(ANCHOR_ERROR_CODE_OFFSET);
+    for code in [first_unnamed, first_unnamed + 1, first_unnamed + 42] {
+        let error = LockProgramError::from_code(code).unwrap();
+        assert_eq!(
+            error,
+            LockProgramError::Program(code - ANCHOR_ERROR_CODE_OFFSET)
+        );
+        assert_eq!(error.code(), code);
+        assert!(!error.message().is_empty());
+    }
+    assert_eq!(
+        LockProgramError::from_program_error(&ProgramError::InvalidArgument),
+        None
+    );
+}
+
+#[test]
+fn loaders_fail_with_locking_errors() {
+    let state = LockedCpLiquidityState::builder();
+    let load = |account: &TestAccount| {
+        LockAccount::from_account_info(account.account_info())
+            .err()
+            .and_then(|e| LockingError::from_program_error(&e))
+    };
+
+    let account = state.to_test_account([1; 32]);
+    assert!(LockedCpLiquidityState::from_account_info(account.account_info()).is_ok());
+    assert_eq!(load(&account), None);
+
+    let data = state.to_account_bytes();
+    let wrong_owner = TestAccount::new([1; 32], [2; 32], 1, &data);
+    assert_eq!(load(&wrong_owner), Some(LockingError::InvalidAccountOwner));
+
+    let short = TestAccount::new([1; 32], ID, 1, &data[..7]);
+    assert_eq!(load(&short), Some(LockingError::InvalidAccountLength));
+    let truncated = TestAccount::new([1; 32], ID, 1, &data[..data.len() - 1]);
+    assert_eq!(
+        LockedCpLiquidityState::from_account_info(truncated.account_info()).err(),
+        Some(LockingError::InvalidAccountLength.into())
+    );
+
+    let mut unknown = data.clone();
+    unknown[0] ^= 1;
+    let unknown = TestAccount::new([1; 32], ID, 1, &unknown);
+    assert_eq!(
+        load(&unknown),
+        Some(LockingError::InvalidAccountDiscriminator)
+    );
+}
@@ -0,0 +1,73 @@
+use pinocchio_raydium_locking_program::{
+    filters::{self, AccountFilter},
+    states::{LockedClmmPositionState, LockedCpLiquidityState},
+};
+
+fn matches(filters: &[AccountFilter], data: &[u8]) -> bool {
+    filters.iter().all(|filter| filter.matches(data))
+}
+
+#[test]
+fn offsets_follow_state_layouts() {
+    assert_eq!(LockedCpLiquidityState::POOL_ID_OFFSET, 64);
+    assert_eq!(LockedCpLiquidityState::FEE_NFT_MINT_OFFSET, 96);
+    assert_eq!(LockedCpLiquidityState::LOCKED_OWNER_OFFSET, 128);
+    assert_eq!(LockedClmmPositionState::POSITION_OWNER_OFFSET, 9);
+    assert_eq!(LockedClmmPositionState::POOL_ID_OFFSET, 41);
+    assert_eq!(LockedClmmPositionState::FEE_NFT_MINT_OFFSET, 137);
+}
+
+#[test]
+fn cp_filters_select_by_field() {
+    let lock = LockedCpLiquidityState::builder()
+        .pool_id([1; 32])
+        .locked_owner([2; 32])
+        .fee_nft_mint([3; 32])
+        .to_account_bytes();
+
+    assert!(matches(&filters::cp_locks(), &lock));
+    assert!(matches(&filters::cp_locks_by_pool(&[1; 32]), &lock));
+    assert!(matches(&filters::cp_locks_by_owner(&[2; 32]), &lock));
+    assert!(matches(&filters::cp_lock_by_fee_nft_mint(&[3; 32]), &lock));
+
+    assert!(!matches(&filters::cp_locks_by_pool(&[2; 32]), &lock));
+    assert!(!matches(&filters::cp_locks_by_owner(&[1; 32]), &lock));
+    assert!(!matches(&filters::cp_locks(), &lock[..lock.len() - 1]));
+}
+
+#[test]
+fn clmm_filters_select_by_field() {
+    let lock = LockedClmmPositionState::builder()
+        .position_owner([4; 32])
+        .pool_id([5; 32])
+        .fee_nft_mint([6; 32])
+        .to_account_bytes();
+
+    assert!(matches(&filters::clmm_locks_by_owner(&[4; 32]), &lock));
+    assert!(matches(&filters::clmm_locks_by_pool(&[5; 32]), &lock));
+    assert!(matches(
+        &filters::clmm_lock_by_fee_nft_mint(&[6; 32]),
+        &lock
+    ));
+    assert!(!matches(&filters::clmm_locks_by_pool(&[4; 32]), &lock));
+}
+
+#[test]
+fn filters_do_not_mix_account_types() {
+    let cp_lock = LockedCpLiquidityState::builder()
+        .pool_id([1; 32])
+        .to_account_bytes();
+    let clmm_lock = LockedClmmPositionState::builder()
+        .pool_id([1; 32])
+        .to_account_bytes();
+
+    assert!(!matches(&filters::clmm_locks(), &cp_lock));
+    assert!(!matches(&filters::cp_locks(), &clmm_lock));
+    assert_eq!(
+        filters::cp_locks()[1],
+        AccountFilter::Memcmp {
+            offset: 0,
+            bytes: LockedCpLiquidityState::DISCRIMINATOR.to_vec()
+        }
+    );
+}
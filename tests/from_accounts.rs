@@ -0,0 +1,95 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+use pinocchio_raydium_locking_program::{
+    instructions::{CollectClmmFeeAndReward, CollectCpFee, LockClmmPosition, LockCpLiquidity},
+    test_utils::{infos, TestAccount},
+};
+
+fn test_accounts(len: usize) -> Vec<TestAccount> {
+    (0..len)
+        .map(|i| TestAccount::new([i as u8; 32], [0; 32], 0, &[]))
+        .collect()
+}
+
+fn keys(accounts: &[&AccountInfo]) -> Vec<Pubkey> {
+    accounts.iter().map(|account| *account.key()).collect()
+}
+
+fn slice_keys(accounts: &[AccountInfo]) -> Vec<Pubkey> {
+    accounts.iter().map(|account| *account.key()).collect()
+}
+
+// Checks that `from_accounts` assigns the accounts in table order, returns the
+// rest and rejects a slice one account short
+macro_rules! check_fixed {
+    ($name:ident, $($arg:expr),*) => {{
+        let len = $name::ACCOUNTS.len();
+        let accounts = test_accounts(len + 2);
+        let infos = infos(&accounts);
+
+        let (instruction, rest) = $name::from_accounts(&infos, $($arg),*).unwrap();
+        let fixed = instruction.with_instruction(|_, accounts| keys(accounts)).unwrap();
+        assert_eq!(fixed, slice_keys(&infos[..len]));
+        assert_eq!(slice_keys(rest), slice_keys(&infos[len..]));
+
+        let (_, rest) = $name::from_accounts(&infos[..len], $($arg),*).unwrap();
+        assert!(rest.is_empty());
+
+        assert_eq!(
+            $name::from_accounts(&infos[..len - 1], $($arg),*).err(),
+            Some(ProgramError::NotEnoughAccountKeys)
+        );
+        assert_eq!(
+            $name::from_accounts(&[], $($arg),*).err(),
+            Some(ProgramError::NotEnoughAccountKeys)
+        );
+    }};
+}
+
+#[test]
+fn fixed_instructions_split_off_trailing_accounts() {
+    check_fixed!(LockCpLiquidity, 1, true);
+    check_fixed!(LockClmmPosition, false);
+    check_fixed!(CollectCpFee, 7);
+}
+
+#[test]
+fn collect_clmm_splits_remaining_accounts_from_the_rest() {
+    let len = CollectClmmFeeAndReward::ACCOUNTS.len();
+    let accounts = test_accounts(len + 5);
+    let infos = infos(&accounts);
+
+    let (collect, rest) = CollectClmmFeeAndReward::from_accounts(&infos, 3).unwrap();
+    assert_eq!(
+        collect
+            .with_instruction(|_, accounts| keys(&accounts[..len]))
+            .unwrap(),
+        slice_keys(&infos[..len])
+    );
+    assert_eq!(
+        slice_keys(collect.remaining_accounts),
+        slice_keys(&infos[len..len + 3])
+    );
+    assert_eq!(slice_keys(rest), slice_keys(&infos[len + 3..]));
+
+    let (collect, rest) = CollectClmmFeeAndReward::from_accounts(&infos, 0).unwrap();
+    assert!(collect.remaining_accounts.is_empty());
+    assert_eq!(rest.len(), 5);
+
+    let (collect, rest) = CollectClmmFeeAndReward::from_accounts(&infos, 5).unwrap();
+    assert_eq!(collect.remaining_accounts.len(), 5);
+    assert!(rest.is_empty());
+}
+
+#[test]
+fn collect_clmm_rejects_missing_accounts() {
+    let len = CollectClmmFeeAndReward::ACCOUNTS.len();
+    let accounts = test_accounts(len + 2);
+    let infos = infos(&accounts);
+
+    for (accounts, remaining_len) in [(&infos[..len - 1], 0), (&infos[..], 3), (&[][..], 0)] {
+        assert_eq!(
+            CollectClmmFeeAndReward::from_accounts(accounts, remaining_len).err(),
+            Some(ProgramError::NotEnoughAccountKeys)
+        );
+    }
+}
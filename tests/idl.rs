@@ -0,0 +1,88 @@
+use std::process::Command;
+
+use pinocchio_raydium_locking_codegen::generate;
+use pinocchio_raydium_locking_program::{
+    discriminator::sha256, instructions::CollectClmmFeeAndReward,
+};
+
+const IDL: &str = include_str!("../idl/raydium_liquidity_locking.json");
+
+#[test]
+fn generated_code_matches_idl() {
+    let generated = generate(IDL).unwrap();
+
+    assert!(
+        generated == include_str!("../src/generated.rs"),
+        "src/generated.rs is stale; run `cargo run -p pinocchio-raydium-locking-codegen`"
+    );
+    assert!(
+        !generated.contains("\n\n\n"),
+        "generated code has double blank lines"
+    );
+}
+
+// Both crates, the generated bindings included, stay `cargo fmt` clean, so
+// regenerating never mixes formatting churn into a diff
+#[test]
+fn workspace_is_formatted() {
+    let output = Command::new(env!("CARGO"))
+        .args(["fmt", "--all", "--check"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "run `cargo fmt --all`:\n{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn idl_matches_its_recorded_hash() {
+    let hash: String = sha256(IDL.as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect();
+
+    assert!(
+        include_str!("../idl/SOURCE.md").contains(&format!("sha256: `{hash}`")),
+        "idl/SOURCE.md does not record the IDL's sha256 {hash}"
+    );
+}
+
+// `collect_clmm_fees_and_rewards` is the deployed instruction name; the IDL-generated
+// carbon-raydium-liquidity-locking-decoder 2.0.0 matches these bytes
+#[test]
+fn collect_clmm_discriminator_is_pinned() {
+    assert_eq!(
+        CollectClmmFeeAndReward::DISCRIMINATOR,
+        [16, 72, 250, 198, 14, 162, 212, 19]
+    );
+}
+
+#[test]
+fn idl_errors_generate_named_codes() {
+    let idl = r#"{
+        "errors": [
+            { "code": 6000, "name": "AmountTooSmall", "msg": "Lock amount is too small" },
+            { "code": 6001, "name": "Unnamed" }
+        ]
+    }"#;
+    let generated = generate(idl).unwrap();
+
+    for line in [
+        "    /// Lock amount is too small\n    AmountTooSmall,",
+        "    pub const ALL: [Self; 2] = [Self::AmountTooSmall, Self::Unnamed];",
+        "            Self::AmountTooSmall => 6000,",
+        "            Self::Unnamed => 6001,",
+        "            Self::AmountTooSmall => \"Lock amount is too small\",",
+        "            Self::Unnamed => \"Unnamed\",",
+    ] {
+        assert!(
+            generated.contains(line),
+            "missing `{line}` in:\n{generated}"
+        );
+    }
+}
@@ -0,0 +1,170 @@
+use pinocchio::{
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::instructions::{Instructions, INSTRUCTIONS_ID},
+};
+use pinocchio_raydium_locking_program::{
+    client::{self, Instruction},
+    error::LockingError,
+    introspection::{assert_cp_lp_locked, cp_lp_locked, LockedLiquidity, LockedLiquidityIter},
+    programs::SYSTEM_PROGRAM_ID,
+    test_utils::TestAccount,
+};
+
+const POOL: Pubkey = [1; 32];
+const OTHER_POOL: Pubkey = [2; 32];
+const LP_MINT: Pubkey = [3; 32];
+const OWNER: Pubkey = [4; 32];
+const PERSONAL_POSITION: Pubkey = [5; 32];
+const POSITION_NFT_MINT: Pubkey = [6; 32];
+
+fn lock_cp(pool: Pubkey, lp_amount: u64) -> Instruction {
+    client::LockCpLiquidity {
+        pool_state: pool,
+        lp_mint: LP_MINT,
+        liquidity_owner: OWNER,
+        lp_amount,
+        ..Default::default()
+    }
+    .instruction()
+}
+
+fn lock_clmm() -> Instruction {
+    client::LockClmmPosition {
+        personal_position: PERSONAL_POSITION,
+        position_nft_mint: POSITION_NFT_MINT,
+        position_nft_owner: OWNER,
+        ..Default::default()
+    }
+    .instruction()
+}
+
+// Serializes `instructions` the way the runtime lays out the Instructions
+// sysvar: count, offsets, then each instruction's metas, program and data,
+// followed by the current instruction index.
+fn sysvar_data(instructions: &[Instruction]) -> Vec<u8> {
+    let mut data = Vec::new();
+    data.extend_from_slice(&(instructions.len() as u16).to_le_bytes());
+    data.resize(2 + 2 * instructions.len(), 0);
+
+    for (i, instruction) in instructions.iter().enumerate() {
+        let offset = data.len() as u16;
+        data[2 + 2 * i..4 + 2 * i].copy_from_slice(&offset.to_le_bytes());
+
+        data.extend_from_slice(&(instruction.accounts.len() as u16).to_le_bytes());
+        for meta in &instruction.accounts {
+            data.push(meta.is_signer as u8 | (meta.is_writable as u8) << 1);
+            data.extend_from_slice(&meta.pubkey);
+        }
+        data.extend_from_slice(&instruction.program_id);
+        data.extend_from_slice(&(instruction.data.len() as u16).to_le_bytes());
+        data.extend_from_slice(&instruction.data);
+    }
+
+    data.extend_from_slice(&0u16.to_le_bytes());
+    data
+}
+
+fn transaction() -> Vec<Instruction> {
+    let mut wrong_program = lock_cp(POOL, 10_000);
+    wrong_program.program_id = [9; 32];
+
+    let mut truncated = lock_cp(POOL, 20_000);
+    truncated.data.pop();
+
+    vec![
+        lock_cp(POOL, 1_000),
+        client::CollectCpFee {
+            pool_state: POOL,
+            fee_lp_amount: 5,
+            ..Default::default()
+        }
+        .instruction(),
+        wrong_program,
+        truncated,
+        lock_clmm(),
+        lock_cp(OTHER_POOL, 7),
+        lock_cp(POOL, 500),
+    ]
+}
+
+fn sysvar(data: &[u8]) -> Instructions<&[u8]> {
+    unsafe { Instructions::new_unchecked(data) }
+}
+
+#[test]
+fn iterates_over_lock_instructions_of_the_lock_program() {
+    let data = sysvar_data(&transaction());
+    let instructions = sysvar(&data);
+
+    let locked: Vec<_> = LockedLiquidityIter::new(&instructions).collect();
+    assert_eq!(
+        locked,
+        [
+            LockedLiquidity::Cp {
+                pool: POOL,
+                lp_mint: LP_MINT,
+                owner: OWNER,
+                lp_amount: 1_000
+            },
+            LockedLiquidity::Clmm {
+                personal_position: PERSONAL_POSITION,
+                position_nft_mint: POSITION_NFT_MINT,
+                owner: OWNER,
+            },
+            LockedLiquidity::Cp {
+                pool: OTHER_POOL,
+                lp_mint: LP_MINT,
+                owner: OWNER,
+                lp_amount: 7
+            },
+            LockedLiquidity::Cp {
+                pool: POOL,
+                lp_mint: LP_MINT,
+                owner: OWNER,
+                lp_amount: 500
+            },
+        ]
+    );
+}
+
+#[test]
+fn sums_lp_locked_per_pool() {
+    let data = sysvar_data(&transaction());
+    let instructions = sysvar(&data);
+
+    assert_eq!(cp_lp_locked(&instructions, &POOL), 1_500);
+    assert_eq!(cp_lp_locked(&instructions, &OTHER_POOL), 7);
+    assert_eq!(cp_lp_locked(&instructions, &[8; 32]), 0);
+
+    let data = sysvar_data(&[]);
+    assert_eq!(cp_lp_locked(&sysvar(&data), &POOL), 0);
+}
+
+#[test]
+fn asserts_lp_locked_from_the_sysvar_account() {
+    let data = sysvar_data(&transaction());
+    let account = TestAccount::new(INSTRUCTIONS_ID, SYSTEM_PROGRAM_ID, 1, &data);
+    let sysvar = account.account_info();
+
+    assert_eq!(assert_cp_lp_locked(sysvar, &POOL, 1_500), Ok(()));
+    assert_eq!(
+        assert_cp_lp_locked(sysvar, &POOL, 1_501),
+        Err(LockingError::LiquidityNotLocked.into())
+    );
+    assert_eq!(
+        assert_cp_lp_locked(sysvar, &[8; 32], 1),
+        Err(LockingError::LiquidityNotLocked.into())
+    );
+}
+
+#[test]
+fn rejects_an_account_other_than_the_sysvar() {
+    let data = sysvar_data(&transaction());
+    let account = TestAccount::new([7; 32], SYSTEM_PROGRAM_ID, 1, &data);
+
+    assert_eq!(
+        assert_cp_lp_locked(account.account_info(), &POOL, 0),
+        Err(ProgramError::UnsupportedSysvar)
+    );
+}
@@ -0,0 +1,190 @@
+use pinocchio_raydium_locking_program::{
+    math::{
+        cp_claimable_fee_lp, cp_fee_lp_delta, cp_lp_to_token_amounts, mul_div_ceil, mul_div_floor,
+        preview_cp_fee, sqrt_u128, CpFeePreview, CpPoolAmounts,
+    },
+    states::LockedCpLiquidityState,
+};
+use proptest::prelude::*;
+
+fn locked_cp_liquidity_state(
+    locked: u64,
+    unclaimed: u64,
+    last_lp: u64,
+    last_k: u128,
+) -> [u8; LockedCpLiquidityState::LEN] {
+    let mut data = [0u8; LockedCpLiquidityState::LEN];
+    data[..8].copy_from_slice(&LockedCpLiquidityState::DISCRIMINATOR);
+    for (offset, bytes) in [
+        (
+            LockedCpLiquidityState::LOCKED_LP_AMOUNT_OFFSET,
+            &locked.to_le_bytes()[..],
+        ),
+        (
+            LockedCpLiquidityState::UNCLAIMED_LP_AMOUNT_OFFSET,
+            &unclaimed.to_le_bytes(),
+        ),
+        (
+            LockedCpLiquidityState::LAST_LP_OFFSET,
+            &last_lp.to_le_bytes(),
+        ),
+        (LockedCpLiquidityState::LAST_K_OFFSET, &last_k.to_le_bytes()),
+    ] {
+        data[offset..offset + bytes.len()].copy_from_slice(bytes);
+    }
+    data
+}
+
+// Bitwise integer square root, independent of the Newton iteration under test
+fn reference_sqrt(value: u128) -> u128 {
+    let mut root = 0u128;
+    for bit in (0..64).rev() {
+        let candidate = root | (1 << bit);
+        if candidate * candidate <= value {
+            root = candidate;
+        }
+    }
+    root
+}
+
+// Settlement with plain u128 arithmetic, valid while nothing overflows
+fn reference_delta(locked: u64, last_lp: u64, last_k: u128, curr_lp: u64, curr_k: u128) -> u64 {
+    let numerator = reference_sqrt(last_k) * curr_lp as u128;
+    let denominator = reference_sqrt(curr_k) * last_lp as u128;
+    if numerator >= denominator {
+        return 0;
+    }
+    let remaining = (locked as u128 * numerator).div_ceil(denominator);
+    locked - remaining as u64
+}
+
+#[test]
+fn fee_lp_delta_without_growth_is_zero() {
+    assert_eq!(
+        cp_fee_lp_delta(1_000, 10_000, 1_000_000, 10_000, 1_000_000),
+        Some(0)
+    );
+    // LP value dropped, nothing to settle
+    assert_eq!(
+        cp_fee_lp_delta(1_000, 10_000, 1_000_000, 10_000, 900_000),
+        Some(0)
+    );
+}
+
+#[test]
+fn fee_lp_delta_after_fee_growth() {
+    // sqrt(k) grows from 1_000 to 1_100 at constant LP supply: 1/11 of the
+    // locked LP is fees
+    assert_eq!(
+        cp_fee_lp_delta(11_000, 5_000, 1_000_000, 5_000, 1_210_000),
+        Some(1_000)
+    );
+}
+
+#[test]
+fn fee_lp_delta_rejects_zero_supply() {
+    assert_eq!(
+        cp_fee_lp_delta(1_000, 0, 1_000_000, 10_000, 1_000_000),
+        None
+    );
+    assert_eq!(
+        cp_fee_lp_delta(1_000, 10_000, 1_000_000, 0, 1_000_000),
+        None
+    );
+}
+
+#[test]
+fn preview_includes_unclaimed_fee_lp() {
+    let data = locked_cp_liquidity_state(11_000, 500, 5_000, 1_000_000);
+    let state = unsafe { LockedCpLiquidityState::from_bytes(&data[8..]) };
+    let pool = CpPoolAmounts {
+        token_0_vault_amount: 1_100,
+        token_1_vault_amount: 1_100,
+        lp_supply: 5_000,
+    };
+
+    assert_eq!(cp_claimable_fee_lp(state, &pool), Some(1_500));
+    assert_eq!(
+        preview_cp_fee(state, &pool),
+        Some(CpFeePreview {
+            fee_lp_amount: 1_500,
+            token_0_amount: 330,
+            token_1_amount: 330,
+        })
+    );
+}
+
+#[test]
+fn lp_to_token_amounts_rounds_down() {
+    let pool = CpPoolAmounts {
+        token_0_vault_amount: 10,
+        token_1_vault_amount: u64::MAX,
+        lp_supply: 3,
+    };
+    assert_eq!(cp_lp_to_token_amounts(1, &pool), Some((3, u64::MAX / 3)));
+    assert_eq!(cp_lp_to_token_amounts(1, &CpPoolAmounts::default()), None);
+}
+
+#[test]
+fn mul_div_edges() {
+    assert_eq!(
+        mul_div_floor(u128::MAX, u128::MAX, u128::MAX),
+        Some(u128::MAX)
+    );
+    assert_eq!(mul_div_floor(u128::MAX, 2, 1), None);
+    assert_eq!(mul_div_floor(1, 1, 0), None);
+    assert_eq!(
+        mul_div_ceil(u128::MAX, u128::MAX - 1, u128::MAX),
+        Some(u128::MAX - 1)
+    );
+    assert_eq!(mul_div_ceil(7, 3, 2), Some(11));
+}
+
+proptest! {
+    #[test]
+    fn sqrt_is_floor_root(value: u128) {
+        let root = sqrt_u128(value) as u128;
+        prop_assert_eq!(root, reference_sqrt(value));
+    }
+
+    #[test]
+    fn mul_div_matches_u128_when_product_fits(a: u64, b: u64, denominator in 1u128..) {
+        let product = a as u128 * b as u128;
+        prop_assert_eq!(mul_div_floor(a as u128, b as u128, denominator), Some(product / denominator));
+        prop_assert_eq!(
+            mul_div_ceil(a as u128, b as u128, denominator),
+            Some(product.div_ceil(denominator))
+        );
+    }
+
+    #[test]
+    fn mul_div_cancels_common_factor(a: u128, b in 1u128..) {
+        prop_assert_eq!(mul_div_floor(a, b, b), Some(a));
+        prop_assert_eq!(mul_div_ceil(a, b, b), Some(a));
+    }
+
+    #[test]
+    fn fee_lp_delta_matches_reference(
+        locked in 0u64..1 << 32,
+        last_lp in 1u64..1 << 32,
+        curr_lp in 1u64..1 << 32,
+        last_k in 0u128..1 << 64,
+        curr_k in 0u128..1 << 64,
+    ) {
+        let delta = cp_fee_lp_delta(locked, last_lp, last_k, curr_lp, curr_k);
+        prop_assert_eq!(delta, Some(reference_delta(locked, last_lp, last_k, curr_lp, curr_k)));
+        prop_assert!(delta.unwrap() <= locked);
+    }
+
+    #[test]
+    fn fee_lp_delta_never_overflows(
+        locked: u64,
+        last_lp in 1u64..,
+        curr_lp in 1u64..,
+        last_k: u128,
+        curr_k: u128,
+    ) {
+        let delta = cp_fee_lp_delta(locked, last_lp, last_k, curr_lp, curr_k);
+        prop_assert!(delta.is_some_and(|delta| delta <= locked));
+    }
+}
@@ -0,0 +1,391 @@
+use litesvm::LiteSVM;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+use pinocchio_raydium_locking_program::{
+    client,
+    decode::LockingInstruction,
+    error::LockProgramError,
+    instructions::{CollectCpFee, LockClmmPosition, LockCpLiquidity},
+    pda::{self, find_program_address},
+    programs::{CLMM_PROGRAM_ID, SYSTEM_PROGRAM_ID},
+    raydium::clmm::PersonalPositionState,
+    states::{LockedClmmPositionState, LockedCpLiquidityState},
+    test_utils::{
+        deploy_native, rent_exempt_lamports, run_native, MockLockProgram, NativeCpi, TestAccount,
+    },
+    ID,
+};
+use solana_account::Account;
+use solana_instruction::{error::InstructionError, AccountMeta, Instruction};
+use solana_keypair::Keypair;
+use solana_program_runtime::declare_process_instruction;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+use solana_transaction_error::TransactionError;
+
+const CALLER: Pubkey = [9; 32];
+const FEE_NFT_SEED: &[u8] = b"fee_nft";
+
+// Caller actions, the first byte of its instruction data
+const LOCK_CP: u8 = 0;
+const LOCK_CP_UNSIGNED: u8 = 1;
+const LOCK_CLMM: u8 = 2;
+const COLLECT_CP: u8 = 3;
+
+fn address(key: Pubkey) -> solana_pubkey::Pubkey {
+    solana_pubkey::Pubkey::new_from_array(key)
+}
+
+fn fee_nft_mint() -> (Pubkey, u8) {
+    find_program_address(&[FEE_NFT_SEED], &CALLER).unwrap()
+}
+
+// A program issuing the lock CPIs through the instruction structs, with the
+// lock program as its last account
+declare_process_instruction!(Caller, 150, |invoke_context| {
+    run_native(invoke_context, call_lock_program)
+});
+
+fn call_lock_program(
+    cpi: &mut NativeCpi,
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> Result<(), ProgramError> {
+    let (&action, args) = data.split_first().unwrap();
+    let amount = u64::from_le_bytes(args[..8].try_into().unwrap());
+    let bump = [fee_nft_mint().1];
+    let fee_nft_mint_seeds: &[&[u8]] = &[FEE_NFT_SEED, &bump];
+
+    match action {
+        LOCK_CP | LOCK_CP_UNSIGNED => {
+            let (lock, _) = LockCpLiquidity::from_accounts(accounts, amount, false)?;
+            let signers: &[&[&[u8]]] = match action {
+                LOCK_CP => &[fee_nft_mint_seeds],
+                _ => &[],
+            };
+            lock.with_instruction(|instruction, _| cpi.invoke_signed(instruction, signers))?
+        }
+        LOCK_CLMM => {
+            let (lock, _) = LockClmmPosition::from_accounts(accounts, true)?;
+            lock.with_instruction(|instruction, _| {
+                cpi.invoke_signed(instruction, &[fee_nft_mint_seeds])
+            })?
+        }
+        COLLECT_CP => {
+            let (collect, _) = CollectCpFee::from_accounts(accounts, amount)?;
+            collect.with_instruction(|instruction, _| cpi.invoke_signed(instruction, &[]))?
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+struct Harness {
+    svm: LiteSVM,
+    mock: MockLockProgram,
+    payer: Keypair,
+    owner: Keypair,
+}
+
+impl Harness {
+    fn new() -> Self {
+        let mut svm = LiteSVM::new();
+        let mock = MockLockProgram::deploy(&mut svm);
+        deploy_native(&mut svm, &CALLER, Caller::vm);
+
+        let payer = Keypair::new();
+        svm.airdrop(&payer.pubkey(), 10_000_000_000).unwrap();
+
+        Self {
+            svm,
+            mock,
+            payer,
+            owner: Keypair::new(),
+        }
+    }
+
+    // Sends `instruction`, signed by the payer and the owner
+    fn send(&mut self, instruction: Instruction) -> Result<(), TransactionError> {
+        self.svm.expire_blockhash();
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, &self.owner],
+            self.svm.latest_blockhash(),
+        );
+        self.svm
+            .send_transaction(transaction)
+            .map(drop)
+            .map_err(|failed| failed.err)
+    }
+
+    // Has the caller CPI into the lock program with the accounts of `lock`
+    fn call(&mut self, action: u8, amount: u64, lock: client::Instruction) -> Result<(), u32> {
+        let mut data = vec![action];
+        data.extend_from_slice(&amount.to_le_bytes());
+
+        let mut accounts: Vec<AccountMeta> = lock
+            .accounts
+            .into_iter()
+            .map(|meta| AccountMeta {
+                pubkey: address(meta.pubkey),
+                // The fee NFT mint is signed for by the caller's seeds
+                is_signer: meta.is_signer && meta.pubkey != fee_nft_mint().0,
+                is_writable: meta.is_writable,
+            })
+            .collect();
+        accounts.push(AccountMeta::new_readonly(address(ID), false));
+
+        self.send(Instruction::new_with_bytes(
+            address(CALLER),
+            &data,
+            accounts,
+        ))
+        .map_err(|error| match error {
+            TransactionError::InstructionError(0, InstructionError::Custom(code)) => code,
+            error => panic!("unexpected error {error:?}"),
+        })
+    }
+
+    fn account(&self, key: Pubkey) -> TestAccount {
+        let account = self.svm.get_account(&address(key)).unwrap();
+        TestAccount::new(
+            key,
+            account.owner.to_bytes(),
+            account.lamports,
+            &account.data,
+        )
+    }
+
+    fn set_account(&mut self, key: Pubkey, owner: Pubkey, data: Vec<u8>) {
+        let account = Account {
+            lamports: rent_exempt_lamports(data.len()),
+            data,
+            owner: address(owner),
+            executable: false,
+            rent_epoch: 0,
+        };
+        self.svm.set_account(address(key), account).unwrap();
+    }
+
+    // Accounts for a valid `lock_cp_liquidity`, each with its own key so the
+    // transaction does not merge their privileges
+    fn lock_cp(&self) -> client::LockCpLiquidity {
+        let mut keys = client::LockCpLiquidity::default();
+        for (i, role) in LockCpLiquidity::ACCOUNTS.iter().enumerate() {
+            *keys.key_mut(role.name).unwrap() = [i as u8 + 100; 32];
+        }
+
+        let (fee_nft_mint, _) = fee_nft_mint();
+        client::LockCpLiquidity {
+            authority: pda::find_lock_cp_authority().0,
+            payer: self.payer.pubkey().to_bytes(),
+            liquidity_owner: self.owner.pubkey().to_bytes(),
+            fee_nft_mint,
+            locked_liquidity: pda::find_locked_liquidity(&fee_nft_mint).0,
+            system_program: SYSTEM_PROGRAM_ID,
+            ..keys
+        }
+    }
+}
+
+fn anchor_error(error: LockProgramError) -> u32 {
+    error.code()
+}
+
+#[test]
+fn lock_cp_liquidity_creates_locked_state() {
+    let mut harness = Harness::new();
+    let lock = harness.lock_cp();
+
+    harness.call(LOCK_CP, 500, lock.instruction()).unwrap();
+
+    let locked_liquidity = harness.account(lock.locked_liquidity);
+    assert_eq!(
+        locked_liquidity.lamports(),
+        rent_exempt_lamports(LockedCpLiquidityState::LEN)
+    );
+    let state = LockedCpLiquidityState::from_account_info(&locked_liquidity).unwrap();
+    assert_eq!(state.locked_lp_amount(), 500);
+    assert_eq!(state.pool_id(), &lock.pool_state);
+    assert_eq!(state.fee_nft_mint(), &lock.fee_nft_mint);
+    assert_eq!(state.locked_owner(), &lock.liquidity_owner);
+    assert_eq!(state.locked_lp_mint(), &lock.lp_mint);
+
+    let cpis = harness.mock.take_cpis();
+    assert_eq!(cpis.len(), 1);
+    assert_eq!(
+        cpis[0].instruction,
+        Some(LockingInstruction::LockCpLiquidity {
+            lp_amount: 500,
+            with_metadata: false
+        })
+    );
+    assert_eq!(cpis[0].accounts, lock.instruction().accounts);
+    assert_eq!(cpis[0].stack_height, 2);
+    assert_eq!(cpis[0].result, Ok(()));
+    assert!(harness.mock.cpis().is_empty());
+
+    // Anchor's `init` refuses an account that already exists
+    assert_eq!(
+        harness.call(LOCK_CP, 500, lock.instruction()),
+        Err(anchor_error(
+            LockProgramError::AccountDiscriminatorAlreadySet
+        ))
+    );
+}
+
+#[test]
+fn lock_cp_liquidity_enforces_privileges_and_seeds() {
+    let mut harness = Harness::new();
+    let lock = harness.lock_cp();
+
+    // Without the caller's seeds the runtime refuses to pass the fee NFT
+    // mint as a signer, before the lock program runs
+    let mut data = vec![LOCK_CP_UNSIGNED];
+    data.extend_from_slice(&1u64.to_le_bytes());
+    let accounts = lock
+        .instruction()
+        .accounts
+        .into_iter()
+        .map(|meta| AccountMeta {
+            pubkey: address(meta.pubkey),
+            is_signer: meta.is_signer && meta.pubkey != lock.fee_nft_mint,
+            is_writable: meta.is_writable,
+        })
+        .chain([AccountMeta::new_readonly(address(ID), false)])
+        .collect();
+    assert_eq!(
+        harness.send(Instruction::new_with_bytes(
+            address(CALLER),
+            &data,
+            accounts
+        )),
+        Err(TransactionError::InstructionError(
+            0,
+            InstructionError::PrivilegeEscalation
+        ))
+    );
+    assert!(harness.mock.cpis().is_empty());
+
+    let mut wrong_pda = lock.clone();
+    wrong_pda.locked_liquidity = [1; 32];
+    assert_eq!(
+        harness.call(LOCK_CP, 1, wrong_pda.instruction()),
+        Err(anchor_error(LockProgramError::ConstraintSeeds))
+    );
+
+    let cpis = harness.mock.take_cpis();
+    assert_eq!(cpis.len(), 1);
+    assert_eq!(
+        cpis[0].result,
+        Err(InstructionError::Custom(anchor_error(
+            LockProgramError::ConstraintSeeds
+        )))
+    );
+}
+
+#[test]
+fn collect_cp_fee_claims_up_to_unclaimed_amount() {
+    let mut harness = Harness::new();
+    let locked_liquidity = [3; 32];
+    let state = LockedCpLiquidityState::builder()
+        .locked_lp_amount(500)
+        .unclaimed_lp_amount(30)
+        .to_account_bytes();
+    harness.set_account(locked_liquidity, ID, state);
+
+    let collect = client::CollectCpFee {
+        authority: pda::find_lock_cp_authority().0,
+        fee_nft_owner: harness.owner.pubkey().to_bytes(),
+        locked_liquidity,
+        ..Default::default()
+    };
+    harness.call(COLLECT_CP, 20, collect.instruction()).unwrap();
+    harness.call(COLLECT_CP, 20, collect.instruction()).unwrap();
+
+    let account = harness.account(locked_liquidity);
+    let state = LockedCpLiquidityState::from_account_info(&account).unwrap();
+    assert_eq!(state.claimed_lp_amount(), 30);
+    assert_eq!(state.unclaimed_lp_amount(), 0);
+    assert_eq!(state.locked_lp_amount(), 500);
+    assert_eq!(harness.mock.cpis().len(), 2);
+}
+
+#[test]
+fn lock_clmm_position_reads_personal_position() {
+    let mut harness = Harness::new();
+    let (fee_nft_mint, _) = fee_nft_mint();
+    let (locked_position, locked_bump) = pda::find_locked_position(&fee_nft_mint);
+
+    let pool_id = [42; 32];
+    let mut personal_position = vec![0; PersonalPositionState::LEN];
+    personal_position[..8].copy_from_slice(&PersonalPositionState::DISCRIMINATOR);
+    personal_position[41..73].copy_from_slice(&pool_id);
+    harness.set_account([5; 32], CLMM_PROGRAM_ID, personal_position);
+
+    let lock = client::LockClmmPosition {
+        authority: pda::find_lock_clmm_authority().0,
+        payer: harness.payer.pubkey().to_bytes(),
+        position_nft_owner: harness.owner.pubkey().to_bytes(),
+        personal_position: [5; 32],
+        locked_nft_account: [6; 32],
+        locked_position,
+        fee_nft_mint,
+        system_program: SYSTEM_PROGRAM_ID,
+        with_metadata: true,
+        ..Default::default()
+    };
+    harness.call(LOCK_CLMM, 0, lock.instruction()).unwrap();
+
+    let account = harness.account(locked_position);
+    let state = LockedClmmPositionState::from_account_info(&account).unwrap();
+    assert_eq!(state.bump(), locked_bump);
+    assert_eq!(state.pool_id(), &pool_id);
+    assert_eq!(state.position_id(), &[5; 32]);
+    assert_eq!(state.position_owner(), &lock.position_nft_owner);
+    assert_eq!(state.locked_nft_account(), &[6; 32]);
+    assert_eq!(state.fee_nft_mint(), &fee_nft_mint);
+}
+
+#[test]
+fn rejects_unknown_instructions_and_missing_accounts() {
+    let mut harness = Harness::new();
+    let lock = harness.lock_cp().instruction();
+    let accounts: Vec<AccountMeta> = lock
+        .accounts
+        .iter()
+        .map(|meta| AccountMeta {
+            pubkey: address(meta.pubkey),
+            // The fee NFT mint is a PDA, so it cannot sign the transaction
+            is_signer: meta.is_signer && meta.pubkey != fee_nft_mint().0,
+            is_writable: meta.is_writable,
+        })
+        .collect();
+    let mut send = |data: &[u8], accounts: &[AccountMeta]| {
+        harness
+            .send(Instruction::new_with_bytes(
+                address(ID),
+                data,
+                accounts.to_vec(),
+            ))
+            .unwrap_err()
+    };
+    let custom = |error| TransactionError::InstructionError(0, InstructionError::Custom(error));
+
+    assert_eq!(
+        send(&[0; 16], &accounts),
+        custom(anchor_error(LockProgramError::InstructionFallbackNotFound))
+    );
+    assert_eq!(
+        send(&lock.data, &accounts[..5]),
+        custom(anchor_error(LockProgramError::AccountNotEnoughKeys))
+    );
+    assert_eq!(
+        send(&lock.data, &accounts),
+        custom(anchor_error(LockProgramError::AccountNotSigner))
+    );
+
+    let cpis = harness.mock.take_cpis();
+    assert_eq!(cpis.len(), 3);
+    assert!(cpis.iter().all(|cpi| cpi.stack_height == 1));
+}
@@ -0,0 +1,159 @@
+use pinocchio::pubkey::{Pubkey, MAX_SEEDS, MAX_SEED_LEN};
+use pinocchio_pubkey::pubkey;
+use pinocchio_raydium_locking_program::{
+    pda::{
+        create_associated_token_account_with_bump, create_cpmm_authority_with_bump,
+        create_lock_clmm_authority_with_bump, create_lock_cp_authority_with_bump,
+        create_locked_liquidity_with_bump, create_locked_lp_vault_with_bump,
+        create_metadata_account_with_bump, find_associated_token_account, find_cpmm_authority,
+        find_fee_nft_account, find_lock_clmm_authority, find_lock_cp_authority,
+        find_locked_liquidity, find_locked_lp_vault, find_locked_nft_account, find_locked_position,
+        find_metadata_account, find_program_address, CPMM_AUTHORITY, CPMM_AUTH_SEED,
+        LOCK_CLMM_AUTHORITY, LOCK_CP_AUTHORITY, METADATA_SEED,
+    },
+    programs::{
+        ASSOCIATED_TOKEN_PROGRAM_ID, CPMM_PROGRAM_ID, METADATA_PROGRAM_ID, TOKEN_2022_PROGRAM_ID,
+        TOKEN_PROGRAM_ID,
+    },
+    states::{LOCKED_LIQUIDITY_SEED, LOCKED_POSITION_SEED, LOCK_CLMM_AUTH_SEED, LOCK_CP_AUTH_SEED},
+    ID,
+};
+
+const USDC_MINT: Pubkey = pubkey!("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+const WALLET: Pubkey = [42; 32];
+
+// Independent derivation by the Solana SDK
+fn reference(seeds: &[&[u8]], program_id: &Pubkey) -> (Pubkey, u8) {
+    let (address, bump) = solana_pubkey::Pubkey::find_program_address(
+        seeds,
+        &solana_pubkey::Pubkey::new_from_array(*program_id),
+    );
+    (address.to_bytes(), bump)
+}
+
+// Addresses as used by the deployed lock and CPMM programs on mainnet
+#[test]
+fn authorities_match_mainnet() {
+    assert_eq!(
+        find_program_address(&[LOCK_CP_AUTH_SEED.as_bytes()], &ID),
+        Some(find_lock_cp_authority())
+    );
+    assert_eq!(
+        find_program_address(&[LOCK_CLMM_AUTH_SEED.as_bytes()], &ID),
+        Some(find_lock_clmm_authority())
+    );
+    assert_eq!(
+        find_program_address(&[CPMM_AUTH_SEED.as_bytes()], &CPMM_PROGRAM_ID),
+        Some(find_cpmm_authority())
+    );
+
+    assert_eq!(
+        find_lock_cp_authority().0,
+        pubkey!("3f7GcQFG397GAaEnv51zR6tsTVihYRydnydDD1cXekxH")
+    );
+    assert_eq!(
+        find_lock_clmm_authority().0,
+        pubkey!("kN1kEznaF5Xbd8LYuqtEFcxzWSBk5Fv6ygX6SqEGJVy")
+    );
+    assert_eq!(
+        find_cpmm_authority().0,
+        pubkey!("GpMZbSM2GgvTKHJirzeGfMFoaZ8UR2X7F4v8vHTvxFbL")
+    );
+
+    assert_eq!(
+        create_lock_cp_authority_with_bump(find_lock_cp_authority().1),
+        Ok(LOCK_CP_AUTHORITY)
+    );
+    assert_eq!(
+        create_lock_clmm_authority_with_bump(find_lock_clmm_authority().1),
+        Ok(LOCK_CLMM_AUTHORITY)
+    );
+    assert_eq!(
+        create_cpmm_authority_with_bump(find_cpmm_authority().1),
+        Ok(CPMM_AUTHORITY)
+    );
+}
+
+#[test]
+fn lock_program_pdas_match_the_sdk() {
+    let fee_nft_mint = [3; 32];
+    let locked_liquidity = find_locked_liquidity(&fee_nft_mint);
+    assert_eq!(
+        locked_liquidity,
+        reference(&[LOCKED_LIQUIDITY_SEED.as_bytes(), &fee_nft_mint], &ID)
+    );
+    assert_eq!(
+        create_locked_liquidity_with_bump(&fee_nft_mint, locked_liquidity.1),
+        Ok(locked_liquidity.0)
+    );
+    assert_eq!(
+        find_locked_position(&fee_nft_mint),
+        reference(&[LOCKED_POSITION_SEED.as_bytes(), &fee_nft_mint], &ID)
+    );
+}
+
+#[test]
+fn associated_token_accounts_match_the_sdk() {
+    for token_program in [TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID] {
+        let expected = reference(
+            &[&WALLET, &token_program, &USDC_MINT],
+            &ASSOCIATED_TOKEN_PROGRAM_ID,
+        );
+        let ata = find_associated_token_account(&WALLET, &USDC_MINT, &token_program);
+        assert_eq!(ata, expected);
+        assert_eq!(
+            find_fee_nft_account(&WALLET, &USDC_MINT, &token_program),
+            expected
+        );
+        assert_eq!(
+            create_associated_token_account_with_bump(&WALLET, &USDC_MINT, &token_program, ata.1),
+            Ok(ata.0)
+        );
+    }
+
+    let vault = find_locked_lp_vault(&USDC_MINT, &TOKEN_PROGRAM_ID);
+    assert_eq!(
+        vault,
+        reference(
+            &[&LOCK_CP_AUTHORITY, &TOKEN_PROGRAM_ID, &USDC_MINT],
+            &ASSOCIATED_TOKEN_PROGRAM_ID
+        )
+    );
+    assert_eq!(
+        create_locked_lp_vault_with_bump(&USDC_MINT, &TOKEN_PROGRAM_ID, vault.1),
+        Ok(vault.0)
+    );
+    assert_eq!(
+        find_locked_nft_account(&USDC_MINT, &TOKEN_2022_PROGRAM_ID),
+        reference(
+            &[&LOCK_CLMM_AUTHORITY, &TOKEN_2022_PROGRAM_ID, &USDC_MINT],
+            &ASSOCIATED_TOKEN_PROGRAM_ID
+        )
+    );
+}
+
+#[test]
+fn metadata_account_matches_the_sdk() {
+    let metadata = find_metadata_account(&USDC_MINT);
+    assert_eq!(
+        metadata,
+        reference(
+            &[METADATA_SEED.as_bytes(), &METADATA_PROGRAM_ID, &USDC_MINT],
+            &METADATA_PROGRAM_ID
+        )
+    );
+    assert_eq!(
+        create_metadata_account_with_bump(&USDC_MINT, metadata.1),
+        Ok(metadata.0)
+    );
+}
+
+#[test]
+fn find_program_address_rejects_invalid_seeds() {
+    let seeds = [&[1u8][..]; MAX_SEEDS];
+    assert_eq!(find_program_address(&seeds, &ID), None);
+    assert!(find_program_address(&seeds[1..], &ID).is_some());
+
+    let long_seed = [0u8; MAX_SEED_LEN + 1];
+    assert_eq!(find_program_address(&[&long_seed], &ID), None);
+}
@@ -0,0 +1,91 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+use pinocchio_raydium_locking_program::{
+    instructions::{AccountRole, CollectClmmFeeAndReward, MAX_REMAINING_ACCOUNTS},
+    test_utils::{infos, TestAccount},
+    ID,
+};
+
+const FIXED_ACCOUNTS: usize = CollectClmmFeeAndReward::ACCOUNTS.len();
+
+// The fixed accounts followed by `remaining` accounts cycling through every
+// signer/writable combination
+fn accounts(remaining: usize) -> Vec<TestAccount> {
+    let fixed = CollectClmmFeeAndReward::ACCOUNTS
+        .iter()
+        .enumerate()
+        .map(|(i, role)| TestAccount::for_role([i as u8; 32], role));
+    let remaining = (0..remaining).map(|i| {
+        let role = AccountRole {
+            name: "remaining",
+            is_signer: i & 2 != 0,
+            is_writable: i & 1 != 0,
+        };
+        TestAccount::for_role([i as u8 + 100; 32], &role)
+    });
+    fixed.chain(remaining).collect()
+}
+
+fn collect(infos: &[AccountInfo]) -> CollectClmmFeeAndReward<'_> {
+    let (collect, rest) =
+        CollectClmmFeeAndReward::from_accounts(infos, infos.len() - FIXED_ACCOUNTS).unwrap();
+    assert!(rest.is_empty());
+    collect
+}
+
+#[test]
+fn forwards_no_remaining_accounts() {
+    let accounts = accounts(0);
+    let infos = infos(&accounts);
+    let cpi = collect(&infos);
+    assert!(cpi.remaining_accounts.is_empty());
+
+    let metas = cpi
+        .with_instruction(|instruction, accounts| {
+            assert_eq!(instruction.program_id, &ID);
+            assert_eq!(accounts.len(), FIXED_ACCOUNTS);
+            instruction.accounts.len()
+        })
+        .unwrap();
+    assert_eq!(metas, FIXED_ACCOUNTS);
+    assert_eq!(cpi.invoke(), Ok(()));
+}
+
+#[test]
+fn forwards_up_to_max_remaining_accounts_with_their_own_flags() {
+    let accounts = accounts(MAX_REMAINING_ACCOUNTS);
+    let infos = infos(&accounts);
+    let cpi = collect(&infos);
+    assert_eq!(cpi.remaining_accounts.len(), MAX_REMAINING_ACCOUNTS);
+
+    cpi.with_instruction(|instruction, accounts| {
+        assert_eq!(instruction.accounts.len(), infos.len());
+        assert_eq!(accounts.len(), infos.len());
+        for (meta, info) in instruction.accounts[FIXED_ACCOUNTS..]
+            .iter()
+            .zip(&infos[FIXED_ACCOUNTS..])
+        {
+            assert_eq!(meta.pubkey, info.key());
+            assert_eq!(meta.is_signer, info.is_signer());
+            assert_eq!(meta.is_writable, info.is_writable());
+        }
+        for (account, info) in accounts.iter().zip(&infos) {
+            assert_eq!(account.key(), info.key());
+        }
+    })
+    .unwrap();
+    assert_eq!(cpi.invoke(), Ok(()));
+}
+
+#[test]
+fn rejects_more_than_max_remaining_accounts() {
+    let accounts = accounts(MAX_REMAINING_ACCOUNTS + 1);
+    let infos = infos(&accounts);
+    let cpi = collect(&infos);
+    assert_eq!(cpi.remaining_accounts.len(), MAX_REMAINING_ACCOUNTS + 1);
+
+    assert_eq!(
+        cpi.with_instruction(|_, _| ()),
+        Err(ProgramError::InvalidArgument)
+    );
+    assert_eq!(cpi.invoke(), Err(ProgramError::InvalidArgument));
+}
@@ -0,0 +1,310 @@
+mod common;
+
+use common::put;
+use pinocchio_raydium_locking_program::{
+    raydium::{
+        clmm::{self, PersonalPositionState, RewardInfo, TickArrayState, TickState},
+        cpmm::{AmmConfig, PoolState},
+    },
+    states::{LockAccount, LockedClmmPositionState, LockedCpLiquidityState},
+    test_utils::rent_exempt_lamports,
+    ID,
+};
+
+#[test]
+fn locked_cp_liquidity_state_layout() {
+    assert_eq!(LockedCpLiquidityState::LEN, 256);
+
+    let mut data = [0u8; 256];
+    put(&mut data, 8, &1_111u64.to_le_bytes());
+    put(&mut data, 16, &2_222u64.to_le_bytes());
+    put(&mut data, 24, &3_333u64.to_le_bytes());
+    put(&mut data, 32, &4_444u64.to_le_bytes());
+    put(
+        &mut data,
+        40,
+        &0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10u128.to_le_bytes(),
+    );
+    put(&mut data, 56, &5_555u64.to_le_bytes());
+    put(&mut data, 64, &[0x11; 32]);
+    put(&mut data, 96, &[0x22; 32]);
+    put(&mut data, 128, &[0x33; 32]);
+    put(&mut data, 160, &[0x44; 32]);
+    put(&mut data, 192, &[0xff; 64]);
+
+    let state = unsafe { LockedCpLiquidityState::from_bytes(&data[8..]) };
+    assert_eq!(state.locked_lp_amount(), 1_111);
+    assert_eq!(state.claimed_lp_amount(), 2_222);
+    assert_eq!(state.unclaimed_lp_amount(), 3_333);
+    assert_eq!(state.last_lp(), 4_444);
+    assert_eq!(state.last_k(), 0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10);
+    assert_eq!(state.recent_epoch(), 5_555);
+    assert_eq!(state.pool_id(), &[0x11; 32]);
+    assert_eq!(state.fee_nft_mint(), &[0x22; 32]);
+    assert_eq!(state.locked_owner(), &[0x33; 32]);
+    assert_eq!(state.locked_lp_mint(), &[0x44; 32]);
+}
+
+#[test]
+fn locked_clmm_position_state_layout() {
+    assert_eq!(LockedClmmPositionState::LEN, 241);
+
+    let mut data = [0u8; 241];
+    put(&mut data, 8, &[254]);
+    put(&mut data, 9, &[0x11; 32]);
+    put(&mut data, 41, &[0x22; 32]);
+    put(&mut data, 73, &[0x33; 32]);
+    put(&mut data, 105, &[0x44; 32]);
+    put(&mut data, 137, &[0x55; 32]);
+    put(&mut data, 169, &6_666u64.to_le_bytes());
+    put(&mut data, 177, &[0xff; 64]);
+
+    let state = unsafe { LockedClmmPositionState::from_bytes(&data[8..]) };
+    assert_eq!(state.bump(), 254);
+    assert_eq!(state.position_owner(), &[0x11; 32]);
+    assert_eq!(state.pool_id(), &[0x22; 32]);
+    assert_eq!(state.position_id(), &[0x33; 32]);
+    assert_eq!(state.locked_nft_account(), &[0x44; 32]);
+    assert_eq!(state.fee_nft_mint(), &[0x55; 32]);
+    assert_eq!(state.recent_epoch(), 6_666);
+}
+
+#[test]
+fn locked_cp_liquidity_builder_writes_layout() {
+    let data = LockedCpLiquidityState::builder()
+        .locked_lp_amount(1_111)
+        .claimed_lp_amount(2_222)
+        .unclaimed_lp_amount(3_333)
+        .last_lp(4_444)
+        .last_k(0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10)
+        .recent_epoch(5_555)
+        .pool_id([0x11; 32])
+        .fee_nft_mint([0x22; 32])
+        .locked_owner([0x33; 32])
+        .locked_lp_mint([0x44; 32])
+        .to_account_bytes();
+
+    let mut expected = [0u8; 256];
+    put(&mut expected, 0, &LockedCpLiquidityState::DISCRIMINATOR);
+    put(&mut expected, 8, &1_111u64.to_le_bytes());
+    put(&mut expected, 16, &2_222u64.to_le_bytes());
+    put(&mut expected, 24, &3_333u64.to_le_bytes());
+    put(&mut expected, 32, &4_444u64.to_le_bytes());
+    put(
+        &mut expected,
+        40,
+        &0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10u128.to_le_bytes(),
+    );
+    put(&mut expected, 56, &5_555u64.to_le_bytes());
+    put(&mut expected, 64, &[0x11; 32]);
+    put(&mut expected, 96, &[0x22; 32]);
+    put(&mut expected, 128, &[0x33; 32]);
+    put(&mut expected, 160, &[0x44; 32]);
+    assert_eq!(data, expected);
+}
+
+#[test]
+fn locked_clmm_position_builder_writes_layout() {
+    let data = LockedClmmPositionState::builder()
+        .bump(254)
+        .position_owner([0x11; 32])
+        .pool_id([0x22; 32])
+        .position_id([0x33; 32])
+        .locked_nft_account([0x44; 32])
+        .fee_nft_mint([0x55; 32])
+        .recent_epoch(6_666)
+        .to_account_bytes();
+
+    let mut expected = [0u8; 241];
+    put(&mut expected, 0, &LockedClmmPositionState::DISCRIMINATOR);
+    put(&mut expected, 8, &[254]);
+    put(&mut expected, 9, &[0x11; 32]);
+    put(&mut expected, 41, &[0x22; 32]);
+    put(&mut expected, 73, &[0x33; 32]);
+    put(&mut expected, 105, &[0x44; 32]);
+    put(&mut expected, 137, &[0x55; 32]);
+    put(&mut expected, 169, &6_666u64.to_le_bytes());
+    assert_eq!(data, expected);
+}
+
+#[test]
+fn builder_test_accounts_load_as_lock_accounts() {
+    let builder = LockedCpLiquidityState::builder()
+        .pool_id([7; 32])
+        .locked_lp_amount(10);
+    let account = builder.to_test_account([1; 32]);
+
+    assert!(account.is_owned_by(&ID));
+    assert!(account.is_writable());
+    assert_eq!(
+        account.lamports(),
+        rent_exempt_lamports(LockedCpLiquidityState::LEN)
+    );
+    assert_eq!(
+        *LockedCpLiquidityState::from_account_info(&account).unwrap(),
+        builder.build()
+    );
+
+    let account = LockedClmmPositionState::builder()
+        .bump(1)
+        .to_test_account([2; 32]);
+    assert!(matches!(
+        LockAccount::from_account_info(&account),
+        Ok(LockAccount::ClmmPosition(state)) if state.bump() == 1
+    ));
+}
+
+// Offsets as in the account data, discriminator included, from the
+// raydium-cp-swap `PoolState` and `AmmConfig` structs
+#[test]
+fn cpmm_pool_state_layout() {
+    assert_eq!(PoolState::LEN, 637);
+
+    let mut data = [0u8; 637];
+    for (i, offset) in [8, 40, 72, 104, 136, 168, 200, 232, 264, 296]
+        .into_iter()
+        .enumerate()
+    {
+        put(&mut data, offset, &[i as u8 + 1; 32]);
+    }
+    put(&mut data, 328, &[251, 4, 9, 6, 9]);
+    for (i, offset) in [333, 341, 349, 357, 365, 373, 381].into_iter().enumerate() {
+        put(&mut data, offset, &(1_000 + i as u64).to_le_bytes());
+    }
+    put(&mut data, 389, &[1, 1]);
+    put(&mut data, 397, &2_000u64.to_le_bytes());
+    put(&mut data, 405, &2_001u64.to_le_bytes());
+    put(&mut data, 413, &[0xff; 224]);
+
+    let pool = unsafe { PoolState::from_bytes(&data[8..]) };
+    assert_eq!(pool.amm_config(), &[1; 32]);
+    assert_eq!(pool.pool_creator(), &[2; 32]);
+    assert_eq!(pool.token_0_vault(), &[3; 32]);
+    assert_eq!(pool.token_1_vault(), &[4; 32]);
+    assert_eq!(pool.lp_mint(), &[5; 32]);
+    assert_eq!(pool.token_0_mint(), &[6; 32]);
+    assert_eq!(pool.token_1_mint(), &[7; 32]);
+    assert_eq!(pool.token_0_program(), &[8; 32]);
+    assert_eq!(pool.token_1_program(), &[9; 32]);
+    assert_eq!(pool.observation_key(), &[10; 32]);
+    assert_eq!(pool.auth_bump(), 251);
+    assert_eq!(pool.status(), 4);
+    assert_eq!(pool.lp_mint_decimals(), 9);
+    assert_eq!(pool.mint_0_decimals(), 6);
+    assert_eq!(pool.mint_1_decimals(), 9);
+    assert_eq!(pool.lp_supply(), 1_000);
+    assert_eq!(pool.protocol_fees_token_0(), 1_001);
+    assert_eq!(pool.protocol_fees_token_1(), 1_002);
+    assert_eq!(pool.fund_fees_token_0(), 1_003);
+    assert_eq!(pool.fund_fees_token_1(), 1_004);
+    assert_eq!(pool.open_time(), 1_005);
+    assert_eq!(pool.recent_epoch(), 1_006);
+    assert_eq!(pool.creator_fee_on(), 1);
+    assert!(pool.enable_creator_fee());
+    assert_eq!(pool.creator_fees_token_0(), 2_000);
+    assert_eq!(pool.creator_fees_token_1(), 2_001);
+}
+
+#[test]
+fn cpmm_amm_config_layout() {
+    assert_eq!(AmmConfig::LEN, 236);
+
+    let mut data = [0u8; 236];
+    put(&mut data, 8, &[254, 1]);
+    put(&mut data, 10, &3u16.to_le_bytes());
+    put(&mut data, 12, &2_500u64.to_le_bytes());
+    put(&mut data, 20, &120_000u64.to_le_bytes());
+    put(&mut data, 28, &40_000u64.to_le_bytes());
+    put(&mut data, 36, &150_000_000u64.to_le_bytes());
+    put(&mut data, 44, &[0x11; 32]);
+    put(&mut data, 76, &[0x22; 32]);
+    put(&mut data, 108, &500u64.to_le_bytes());
+    put(&mut data, 116, &[0xff; 120]);
+
+    let config = unsafe { AmmConfig::from_bytes(&data[8..]) };
+    assert_eq!(config.bump(), 254);
+    assert!(config.disable_create_pool());
+    assert_eq!(config.index(), 3);
+    assert_eq!(config.trade_fee_rate(), 2_500);
+    assert_eq!(config.protocol_fee_rate(), 120_000);
+    assert_eq!(config.fund_fee_rate(), 40_000);
+    assert_eq!(config.create_pool_fee(), 150_000_000);
+    assert_eq!(config.protocol_owner(), &[0x11; 32]);
+    assert_eq!(config.fund_owner(), &[0x22; 32]);
+    assert_eq!(config.creator_fee_rate(), 500);
+}
+
+// The offset constants point at the fields the layout tests above and the
+// raydium-clmm structs place them
+#[test]
+fn raydium_field_offsets() {
+    assert_eq!(
+        [
+            PoolState::TOKEN_0_VAULT_OFFSET,
+            PoolState::TOKEN_1_VAULT_OFFSET,
+            PoolState::LP_MINT_OFFSET,
+            PoolState::TOKEN_0_MINT_OFFSET,
+            PoolState::TOKEN_1_MINT_OFFSET,
+            PoolState::LP_SUPPLY_OFFSET,
+            PoolState::CREATOR_FEES_TOKEN_1_OFFSET,
+        ],
+        [72, 104, 136, 168, 200, 333, 405]
+    );
+    assert_eq!(
+        [
+            AmmConfig::TRADE_FEE_RATE_OFFSET,
+            AmmConfig::CREATOR_FEE_RATE_OFFSET
+        ],
+        [12, 108]
+    );
+
+    assert_eq!(
+        [
+            clmm::PoolState::TICK_SPACING_OFFSET,
+            clmm::PoolState::LIQUIDITY_OFFSET,
+            clmm::PoolState::SQRT_PRICE_X64_OFFSET,
+            clmm::PoolState::TICK_CURRENT_OFFSET,
+            clmm::PoolState::FEE_GROWTH_GLOBAL_0_X64_OFFSET,
+            clmm::PoolState::FEE_GROWTH_GLOBAL_1_X64_OFFSET,
+            clmm::PoolState::REWARD_INFOS_OFFSET,
+        ],
+        [235, 237, 253, 269, 277, 293, 397]
+    );
+    assert_eq!(core::mem::size_of::<RewardInfo>(), 169);
+    assert_eq!(
+        [
+            RewardInfo::OPEN_TIME_OFFSET,
+            RewardInfo::EMISSIONS_PER_SECOND_X64_OFFSET,
+            RewardInfo::REWARD_GROWTH_GLOBAL_X64_OFFSET,
+        ],
+        [1, 25, 153]
+    );
+
+    assert_eq!(
+        [
+            PersonalPositionState::POOL_ID_OFFSET,
+            PersonalPositionState::TICK_LOWER_INDEX_OFFSET,
+            PersonalPositionState::LIQUIDITY_OFFSET,
+            PersonalPositionState::TOKEN_FEES_OWED_0_OFFSET,
+            PersonalPositionState::REWARD_INFOS_OFFSET,
+        ],
+        [41, 73, 81, 129, 145]
+    );
+
+    assert_eq!(
+        [
+            TickArrayState::START_TICK_INDEX_OFFSET,
+            TickArrayState::TICKS_OFFSET
+        ],
+        [40, 44]
+    );
+    assert_eq!(core::mem::size_of::<TickState>(), 168);
+    assert_eq!(
+        [
+            TickState::LIQUIDITY_GROSS_OFFSET,
+            TickState::FEE_GROWTH_OUTSIDE_0_X64_OFFSET,
+            TickState::REWARD_GROWTHS_OUTSIDE_X64_OFFSET,
+        ],
+        [20, 36, 68]
+    );
+}
@@ -0,0 +1,102 @@
+use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
+use pinocchio_raydium_locking_program::{
+    error::{LockingError, ValidationError},
+    instructions::LockCpLiquidity,
+    pda,
+    programs::{
+        token_program_for_mint, ASSOCIATED_TOKEN_PROGRAM_ID, METADATA_PROGRAM_ID, RENT_SYSVAR_ID,
+        SYSTEM_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID,
+    },
+    test_utils::{infos, TestAccount},
+};
+
+const FEE_NFT_OWNER: Pubkey = [1; 32];
+const FEE_NFT_MINT: Pubkey = [2; 32];
+const LP_MINT: Pubkey = [3; 32];
+
+fn mint(owner: Pubkey) -> TestAccount {
+    TestAccount::new([9; 32], owner, 1, &[0; 82])
+}
+
+#[test]
+fn picks_the_token_program_owning_the_mint() {
+    assert_eq!(
+        token_program_for_mint(mint(TOKEN_PROGRAM_ID).account_info()),
+        Ok(&TOKEN_PROGRAM_ID)
+    );
+    assert_eq!(
+        token_program_for_mint(mint(TOKEN_2022_PROGRAM_ID).account_info()),
+        Ok(&TOKEN_2022_PROGRAM_ID)
+    );
+    assert_eq!(
+        token_program_for_mint(mint(SYSTEM_PROGRAM_ID).account_info()),
+        Err::<&Pubkey, ProgramError>(LockingError::InvalidAccountOwner.into())
+    );
+}
+
+// `LockCpLiquidity` accounts for a pool whose vaults are owned by
+// `vault_token_program`, with `token_program` as given
+fn lock_cp_accounts(vault_token_program: Pubkey, token_program: Pubkey) -> Vec<TestAccount> {
+    let authority = pda::find_lock_cp_authority().0;
+    LockCpLiquidity::ACCOUNTS
+        .iter()
+        .enumerate()
+        .map(|(i, role)| {
+            let (key, owner) = match role.name {
+                "authority" => (authority, SYSTEM_PROGRAM_ID),
+                "fee_nft_owner" => (FEE_NFT_OWNER, SYSTEM_PROGRAM_ID),
+                "fee_nft_mint" => (FEE_NFT_MINT, SYSTEM_PROGRAM_ID),
+                "fee_nft_account" => (
+                    pda::find_fee_nft_account(&FEE_NFT_OWNER, &FEE_NFT_MINT, &TOKEN_PROGRAM_ID).0,
+                    SYSTEM_PROGRAM_ID,
+                ),
+                "locked_liquidity" => (
+                    pda::find_locked_liquidity(&FEE_NFT_MINT).0,
+                    SYSTEM_PROGRAM_ID,
+                ),
+                "lp_mint" => (LP_MINT, TOKEN_PROGRAM_ID),
+                "locked_lp_vault" => (
+                    pda::find_locked_lp_vault(&LP_MINT, &TOKEN_PROGRAM_ID).0,
+                    SYSTEM_PROGRAM_ID,
+                ),
+                "token_0_vault" | "token_1_vault" => ([i as u8 + 100; 32], vault_token_program),
+                "metadata_account" => (
+                    pda::find_metadata_account(&FEE_NFT_MINT).0,
+                    SYSTEM_PROGRAM_ID,
+                ),
+                "rent" => (RENT_SYSVAR_ID, SYSTEM_PROGRAM_ID),
+                "system_program" => (SYSTEM_PROGRAM_ID, SYSTEM_PROGRAM_ID),
+                "token_program" => (token_program, SYSTEM_PROGRAM_ID),
+                "associated_token_program" => (ASSOCIATED_TOKEN_PROGRAM_ID, SYSTEM_PROGRAM_ID),
+                "metadata_program" => (METADATA_PROGRAM_ID, SYSTEM_PROGRAM_ID),
+                _ => ([i as u8 + 100; 32], SYSTEM_PROGRAM_ID),
+            };
+            TestAccount::for_role(key, role).owned_by(owner)
+        })
+        .collect()
+}
+
+fn validate(accounts: &[TestAccount]) -> Result<(), ValidationError> {
+    let infos = infos(accounts);
+    let (lock, _) = LockCpLiquidity::from_accounts(&infos, 1_000, false).unwrap();
+    lock.validate()
+}
+
+#[test]
+fn locks_pools_with_spl_token_or_token_2022_vaults() {
+    for vault_token_program in [TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID] {
+        let accounts = lock_cp_accounts(vault_token_program, TOKEN_PROGRAM_ID);
+        assert_eq!(validate(&accounts), Ok(()));
+    }
+}
+
+#[test]
+fn requires_the_spl_token_program_of_the_lp_mint() {
+    for vault_token_program in [TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID] {
+        let accounts = lock_cp_accounts(vault_token_program, TOKEN_2022_PROGRAM_ID);
+        assert_eq!(
+            validate(&accounts),
+            Err(ValidationError::IncorrectProgramId("token_program"))
+        );
+    }
+}
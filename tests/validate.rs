@@ -0,0 +1,614 @@
+use pinocchio::{instruction::Seed, pubkey::Pubkey};
+use pinocchio_raydium_locking_program::{
+    error::ValidationError,
+    instructions::{
+        AccountRole, CollectClmmFeeAndReward, CollectCpFee, LockClmmPosition, LockCpLiquidity,
+        MAX_REMAINING_ACCOUNTS,
+    },
+    pda::{self, find_program_address},
+    programs::{
+        ASSOCIATED_TOKEN_PROGRAM_ID, CLMM_PROGRAM_ID, CPMM_PROGRAM_ID, MEMO_PROGRAM_ID,
+        METADATA_PROGRAM_ID, RENT_SYSVAR_ID, SYSTEM_PROGRAM_ID, TOKEN_2022_PROGRAM_ID,
+        TOKEN_PROGRAM_ID,
+    },
+    states::LockedClmmPositionState,
+    test_utils::{infos, TestAccount},
+};
+
+const CALLER: Pubkey = [7; 32];
+const FEE_NFT_SEED: &[u8] = b"fee_nft";
+const FEE_NFT_OWNER: Pubkey = [1; 32];
+const POSITION_NFT_MINT: Pubkey = [3; 32];
+const LP_MINT: Pubkey = [4; 32];
+
+// The fee NFT mint is a PDA of the calling program
+fn fee_nft_mint() -> (Pubkey, u8) {
+    find_program_address(&[FEE_NFT_SEED], &CALLER).unwrap()
+}
+
+// The key the lock program expects for each `LockClmmPosition` account
+fn lock_clmm_key(name: &str) -> Pubkey {
+    let authority = pda::find_lock_clmm_authority().0;
+    let (fee_nft_mint, _) = fee_nft_mint();
+    match name {
+        "authority" => authority,
+        "fee_nft_owner" => FEE_NFT_OWNER,
+        "position_nft_mint" => POSITION_NFT_MINT,
+        "locked_nft_account" => {
+            pda::find_locked_nft_account(&POSITION_NFT_MINT, &TOKEN_PROGRAM_ID).0
+        }
+        "locked_position" => pda::find_locked_position(&fee_nft_mint).0,
+        "fee_nft_mint" => fee_nft_mint,
+        "fee_nft_account" => {
+            pda::find_fee_nft_account(&FEE_NFT_OWNER, &fee_nft_mint, &TOKEN_2022_PROGRAM_ID).0
+        }
+        "metadata_account" => pda::find_metadata_account(&fee_nft_mint).0,
+        "metadata_program" => METADATA_PROGRAM_ID,
+        "associated_token_program" => ASSOCIATED_TOKEN_PROGRAM_ID,
+        "rent" => RENT_SYSVAR_ID,
+        "fee_nft_token_program" => TOKEN_2022_PROGRAM_ID,
+        "locked_nft_token_program" => TOKEN_PROGRAM_ID,
+        "system_program" => SYSTEM_PROGRAM_ID,
+        _ => [name.len() as u8 + 100; 32],
+    }
+}
+
+// The key the lock program expects for each `LockCpLiquidity` account
+fn lock_cp_key(name: &str) -> Pubkey {
+    let authority = pda::find_lock_cp_authority().0;
+    let (fee_nft_mint, _) = fee_nft_mint();
+    match name {
+        "authority" => authority,
+        "fee_nft_owner" => FEE_NFT_OWNER,
+        "fee_nft_mint" => fee_nft_mint,
+        "fee_nft_account" => {
+            pda::find_fee_nft_account(&FEE_NFT_OWNER, &fee_nft_mint, &TOKEN_PROGRAM_ID).0
+        }
+        "locked_liquidity" => pda::find_locked_liquidity(&fee_nft_mint).0,
+        "lp_mint" => LP_MINT,
+        "locked_lp_vault" => pda::find_locked_lp_vault(&LP_MINT, &TOKEN_PROGRAM_ID).0,
+        "metadata_account" => pda::find_metadata_account(&fee_nft_mint).0,
+        "rent" => RENT_SYSVAR_ID,
+        "system_program" => SYSTEM_PROGRAM_ID,
+        "token_program" => TOKEN_PROGRAM_ID,
+        "associated_token_program" => ASSOCIATED_TOKEN_PROGRAM_ID,
+        "metadata_program" => METADATA_PROGRAM_ID,
+        _ => [name.len() as u8 + 100; 32],
+    }
+}
+
+// The key the lock program expects for each `CollectCpFee` account
+fn collect_cp_key(name: &str) -> Pubkey {
+    match name {
+        "authority" => pda::find_lock_cp_authority().0,
+        "fee_nft_owner" => FEE_NFT_OWNER,
+        "cpmm_program" => CPMM_PROGRAM_ID,
+        "cp_authority" => pda::find_cpmm_authority().0,
+        "lp_mint" => LP_MINT,
+        "locked_lp_vault" => pda::find_locked_lp_vault(&LP_MINT, &TOKEN_PROGRAM_ID).0,
+        "token_program" => TOKEN_PROGRAM_ID,
+        "token_program_2022" => TOKEN_2022_PROGRAM_ID,
+        "memo_program" => MEMO_PROGRAM_ID,
+        _ => [name.len() as u8 + 100; 32],
+    }
+}
+
+// The key the lock program expects for each `CollectClmmFeeAndReward` account
+fn collect_clmm_key(name: &str) -> Pubkey {
+    match name {
+        "authority" => pda::find_lock_clmm_authority().0,
+        "fee_nft_owner" => FEE_NFT_OWNER,
+        "locked_position" => pda::find_locked_position(&fee_nft_mint().0).0,
+        "locked_nft_account" => {
+            pda::find_locked_nft_account(&POSITION_NFT_MINT, &TOKEN_PROGRAM_ID).0
+        }
+        "clmm_program" => CLMM_PROGRAM_ID,
+        "token_program" => TOKEN_PROGRAM_ID,
+        "token_program_2022" => TOKEN_2022_PROGRAM_ID,
+        "memo_program" => MEMO_PROGRAM_ID,
+        _ => [name.len() as u8 + 100; 32],
+    }
+}
+
+// Accounts for `roles` with the keys from `key` and the role flags
+fn accounts_for(roles: &[AccountRole], key: fn(&str) -> Pubkey) -> Vec<TestAccount> {
+    roles
+        .iter()
+        .map(|role| TestAccount::for_role(key(role.name), role))
+        .collect()
+}
+
+// Valid `LockClmmPosition` accounts with the role flags
+fn lock_clmm_accounts() -> Vec<TestAccount> {
+    accounts_for(&LockClmmPosition::ACCOUNTS, lock_clmm_key)
+}
+
+fn lock_cp_accounts() -> Vec<TestAccount> {
+    accounts_for(&LockCpLiquidity::ACCOUNTS, lock_cp_key)
+}
+
+fn collect_cp_accounts() -> Vec<TestAccount> {
+    accounts_for(&CollectCpFee::ACCOUNTS, collect_cp_key)
+}
+
+// The locked position records the fee NFT mint and the locked NFT account
+fn collect_clmm_accounts() -> Vec<TestAccount> {
+    let mut accounts = accounts_for(&CollectClmmFeeAndReward::ACCOUNTS, collect_clmm_key);
+    let locked_position = locked_position(collect_clmm_key("locked_position"), fee_nft_mint().0);
+    accounts[position(&CollectClmmFeeAndReward::ACCOUNTS, "locked_position")] = locked_position;
+    accounts
+}
+
+fn locked_position(key: Pubkey, fee_nft_mint: Pubkey) -> TestAccount {
+    LockedClmmPositionState::builder()
+        .fee_nft_mint(fee_nft_mint)
+        .locked_nft_account(collect_clmm_key("locked_nft_account"))
+        .to_test_account(key)
+}
+
+fn position(roles: &[AccountRole], name: &str) -> usize {
+    roles.iter().position(|role| role.name == name).unwrap()
+}
+
+// Replaces the account named by `role` with one at `key` with the flags of
+// `role`
+fn replace(roles: &[AccountRole], accounts: &mut [TestAccount], role: AccountRole, key: Pubkey) {
+    accounts[position(roles, role.name)] = TestAccount::for_role(key, &role);
+}
+
+// `accounts` with the account named `name` moved to `key`, keeping its flags
+fn with_key(
+    roles: &[AccountRole],
+    mut accounts: Vec<TestAccount>,
+    name: &str,
+    key: Pubkey,
+) -> Vec<TestAccount> {
+    replace(roles, &mut accounts, roles[position(roles, name)], key);
+    accounts
+}
+
+// Checks that `validate` names each account whose signer or writable flag is
+// cleared
+fn assert_rejects_missing_flags(
+    roles: &[AccountRole],
+    accounts: fn() -> Vec<TestAccount>,
+    validate: fn(&[TestAccount]) -> Result<(), ValidationError>,
+) {
+    for role in roles {
+        let key = *accounts()[position(roles, role.name)].key();
+        if role.is_signer {
+            let mut accounts = accounts();
+            let role = AccountRole {
+                is_signer: false,
+                ..*role
+            };
+            replace(roles, &mut accounts, role, key);
+            assert_eq!(
+                validate(&accounts),
+                Err(ValidationError::NotSigner(role.name))
+            );
+        }
+        if role.is_writable {
+            let mut accounts = accounts();
+            let role = AccountRole {
+                is_writable: false,
+                ..*role
+            };
+            replace(roles, &mut accounts, role, key);
+            assert_eq!(
+                validate(&accounts),
+                Err(ValidationError::NotWritable(role.name))
+            );
+        }
+    }
+}
+
+fn validate(accounts: &[TestAccount]) -> Result<(), ValidationError> {
+    let infos = infos(accounts);
+    let (lock, _) = LockClmmPosition::from_accounts(&infos, true).unwrap();
+    lock.validate()
+}
+
+fn validate_lock_cp(accounts: &[TestAccount]) -> Result<(), ValidationError> {
+    let infos = infos(accounts);
+    let (lock, _) = LockCpLiquidity::from_accounts(&infos, 1_000, true).unwrap();
+    lock.validate()
+}
+
+fn validate_collect_cp(accounts: &[TestAccount]) -> Result<(), ValidationError> {
+    let infos = infos(accounts);
+    let (collect, _) = CollectCpFee::from_accounts(&infos, 5).unwrap();
+    collect.validate()
+}
+
+fn validate_collect_clmm(accounts: &[TestAccount]) -> Result<(), ValidationError> {
+    let infos = infos(accounts);
+    let remaining = infos.len() - CollectClmmFeeAndReward::ACCOUNTS.len();
+    let (collect, _) = CollectClmmFeeAndReward::from_accounts(&infos, remaining).unwrap();
+    collect.validate()
+}
+
+#[test]
+fn accepts_the_accounts_the_lock_program_expects() {
+    assert_eq!(validate(&lock_clmm_accounts()), Ok(()));
+}
+
+#[test]
+fn rejects_wrong_pdas() {
+    for name in [
+        "authority",
+        "locked_nft_account",
+        "locked_position",
+        "fee_nft_account",
+        "metadata_account",
+    ] {
+        assert_eq!(
+            validate(&with_key(
+                &LockClmmPosition::ACCOUNTS,
+                lock_clmm_accounts(),
+                name,
+                [9; 32]
+            )),
+            Err(ValidationError::InvalidPda(name))
+        );
+    }
+
+    // Derived for the other token program
+    let locked_nft_account =
+        pda::find_locked_nft_account(&POSITION_NFT_MINT, &TOKEN_2022_PROGRAM_ID).0;
+    assert_eq!(
+        validate(&with_key(
+            &LockClmmPosition::ACCOUNTS,
+            lock_clmm_accounts(),
+            "locked_nft_account",
+            locked_nft_account
+        )),
+        Err(ValidationError::InvalidPda("locked_nft_account"))
+    );
+}
+
+#[test]
+fn rejects_wrong_program_accounts() {
+    for name in [
+        "metadata_program",
+        "associated_token_program",
+        "rent",
+        "fee_nft_token_program",
+        "locked_nft_token_program",
+        "system_program",
+    ] {
+        assert_eq!(
+            validate(&with_key(
+                &LockClmmPosition::ACCOUNTS,
+                lock_clmm_accounts(),
+                name,
+                [9; 32]
+            )),
+            Err(ValidationError::IncorrectProgramId(name))
+        );
+    }
+}
+
+#[test]
+fn rejects_missing_signer_and_writable_flags() {
+    assert_rejects_missing_flags(&LockClmmPosition::ACCOUNTS, lock_clmm_accounts, validate);
+}
+
+#[test]
+fn validates_and_invokes_with_a_pda_fee_nft_mint() {
+    let (fee_nft_mint, bump) = fee_nft_mint();
+    // Seed-signed accounts are not transaction signers
+    let mut accounts = lock_clmm_accounts();
+    let roles = &LockClmmPosition::ACCOUNTS;
+    replace(
+        roles,
+        &mut accounts,
+        AccountRole::writable("fee_nft_mint"),
+        fee_nft_mint,
+    );
+    replace(roles, &mut accounts, AccountRole::writable("payer"), CALLER);
+    let infos = infos(&accounts);
+    let (lock, _) = LockClmmPosition::from_accounts(&infos, true).unwrap();
+
+    assert_eq!(lock.validate(), Err(ValidationError::NotSigner("payer")));
+    assert_eq!(
+        lock.validate_for_pda_signers(&[&CALLER]),
+        Err(ValidationError::NotSigner("fee_nft_mint"))
+    );
+    assert_eq!(
+        lock.validate_for_pda_signers(&[&CALLER, &fee_nft_mint]),
+        Ok(())
+    );
+
+    let bump = [bump];
+    let seeds = [Seed::from(FEE_NFT_SEED), Seed::from(&bump)];
+    assert_eq!(lock.invoke_with_fee_nft_mint(&seeds), Ok(()));
+
+    // The CPI still marks them as signers
+    lock.with_instruction(|instruction, _| {
+        for (role, meta) in LockClmmPosition::ACCOUNTS.iter().zip(instruction.accounts) {
+            assert_eq!(meta.is_signer, role.is_signer, "{}", role.name);
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn lock_cp_liquidity_accepts_the_accounts_the_lock_program_expects() {
+    assert_eq!(validate_lock_cp(&lock_cp_accounts()), Ok(()));
+}
+
+#[test]
+fn lock_cp_liquidity_rejects_missing_signer_and_writable_flags() {
+    assert_rejects_missing_flags(
+        &LockCpLiquidity::ACCOUNTS,
+        lock_cp_accounts,
+        validate_lock_cp,
+    );
+}
+
+// A fee NFT mint that is not a transaction signer is named by `validate`, so a
+// plain `invoke` never reaches the lock program without its signature
+#[test]
+fn lock_cp_liquidity_names_an_unsigned_fee_nft_mint() {
+    let (fee_nft_mint, _) = fee_nft_mint();
+    let mut accounts = lock_cp_accounts();
+    replace(
+        &LockCpLiquidity::ACCOUNTS,
+        &mut accounts,
+        AccountRole::writable("fee_nft_mint"),
+        fee_nft_mint,
+    );
+    let infos = infos(&accounts);
+    let (lock, _) = LockCpLiquidity::from_accounts(&infos, 1_000, true).unwrap();
+
+    assert_eq!(
+        lock.validate(),
+        Err(ValidationError::NotSigner("fee_nft_mint"))
+    );
+    assert_eq!(lock.validate_for_pda_signers(&[&fee_nft_mint]), Ok(()));
+}
+
+#[test]
+fn lock_clmm_position_names_an_unsigned_fee_nft_mint() {
+    let (fee_nft_mint, _) = fee_nft_mint();
+    let mut accounts = lock_clmm_accounts();
+    replace(
+        &LockClmmPosition::ACCOUNTS,
+        &mut accounts,
+        AccountRole::writable("fee_nft_mint"),
+        fee_nft_mint,
+    );
+    let infos = infos(&accounts);
+    let (lock, _) = LockClmmPosition::from_accounts(&infos, true).unwrap();
+
+    assert_eq!(
+        lock.validate(),
+        Err(ValidationError::NotSigner("fee_nft_mint"))
+    );
+    assert_eq!(lock.validate_for_pda_signers(&[&fee_nft_mint]), Ok(()));
+}
+
+#[test]
+fn collect_cp_fee_accepts_the_accounts_the_lock_program_expects() {
+    assert_eq!(validate_collect_cp(&collect_cp_accounts()), Ok(()));
+}
+
+#[test]
+fn collect_cp_fee_rejects_wrong_pdas() {
+    for name in ["authority", "cp_authority", "locked_lp_vault"] {
+        let accounts = with_key(
+            &CollectCpFee::ACCOUNTS,
+            collect_cp_accounts(),
+            name,
+            [9; 32],
+        );
+        assert_eq!(
+            validate_collect_cp(&accounts),
+            Err(ValidationError::InvalidPda(name))
+        );
+    }
+
+    // The lock program's authority, not the CLMM one
+    let accounts = with_key(
+        &CollectCpFee::ACCOUNTS,
+        collect_cp_accounts(),
+        "authority",
+        pda::find_lock_clmm_authority().0,
+    );
+    assert_eq!(
+        validate_collect_cp(&accounts),
+        Err(ValidationError::InvalidPda("authority"))
+    );
+
+    // Derived for another LP mint, then for the other token program
+    for locked_lp_vault in [
+        pda::find_locked_lp_vault(&[9; 32], &TOKEN_PROGRAM_ID).0,
+        pda::find_locked_lp_vault(&LP_MINT, &TOKEN_2022_PROGRAM_ID).0,
+    ] {
+        let accounts = with_key(
+            &CollectCpFee::ACCOUNTS,
+            collect_cp_accounts(),
+            "locked_lp_vault",
+            locked_lp_vault,
+        );
+        assert_eq!(
+            validate_collect_cp(&accounts),
+            Err(ValidationError::InvalidPda("locked_lp_vault"))
+        );
+    }
+}
+
+#[test]
+fn collect_cp_fee_rejects_wrong_program_accounts() {
+    for (name, key) in [
+        ("cpmm_program", CLMM_PROGRAM_ID),
+        ("token_program", TOKEN_2022_PROGRAM_ID),
+        ("token_program_2022", TOKEN_PROGRAM_ID),
+        ("memo_program", [9; 32]),
+    ] {
+        let accounts = with_key(&CollectCpFee::ACCOUNTS, collect_cp_accounts(), name, key);
+        assert_eq!(
+            validate_collect_cp(&accounts),
+            Err(ValidationError::IncorrectProgramId(name))
+        );
+    }
+}
+
+#[test]
+fn collect_cp_fee_rejects_missing_signer_and_writable_flags() {
+    assert_rejects_missing_flags(
+        &CollectCpFee::ACCOUNTS,
+        collect_cp_accounts,
+        validate_collect_cp,
+    );
+}
+
+#[test]
+fn collect_cp_fee_validates_a_pda_fee_nft_owner() {
+    let mut accounts = collect_cp_accounts();
+    replace(
+        &CollectCpFee::ACCOUNTS,
+        &mut accounts,
+        AccountRole::readonly("fee_nft_owner"),
+        CALLER,
+    );
+    let infos = infos(&accounts);
+    let (collect, _) = CollectCpFee::from_accounts(&infos, 5).unwrap();
+
+    assert_eq!(
+        collect.validate(),
+        Err(ValidationError::NotSigner("fee_nft_owner"))
+    );
+    assert_eq!(
+        collect.validate_for_pda_signers(&[&[9; 32]]),
+        Err(ValidationError::NotSigner("fee_nft_owner"))
+    );
+    assert_eq!(collect.validate_for_pda_signers(&[&CALLER]), Ok(()));
+}
+
+#[test]
+fn collect_clmm_fee_accepts_the_accounts_the_lock_program_expects() {
+    assert_eq!(validate_collect_clmm(&collect_clmm_accounts()), Ok(()));
+}
+
+#[test]
+fn collect_clmm_fee_rejects_wrong_pdas() {
+    let roles = &CollectClmmFeeAndReward::ACCOUNTS;
+    for (name, key) in [
+        ("authority", [9; 32]),
+        ("authority", pda::find_lock_cp_authority().0),
+        ("locked_nft_account", [9; 32]),
+        // Derived for the other token program
+        (
+            "locked_nft_account",
+            pda::find_locked_nft_account(&POSITION_NFT_MINT, &TOKEN_2022_PROGRAM_ID).0,
+        ),
+    ] {
+        let accounts = with_key(roles, collect_clmm_accounts(), name, key);
+        assert_eq!(
+            validate_collect_clmm(&accounts),
+            Err(ValidationError::InvalidPda(name))
+        );
+    }
+
+    // Another fee NFT's locked position: valid data at the wrong address, then
+    // the right address with data recording another fee NFT mint
+    let locked_position_index = position(roles, "locked_position");
+    let other_locked_position = pda::find_locked_position(&[9; 32]).0;
+    for locked_position in [
+        locked_position(other_locked_position, fee_nft_mint().0),
+        locked_position(collect_clmm_key("locked_position"), [9; 32]),
+    ] {
+        let mut accounts = collect_clmm_accounts();
+        accounts[locked_position_index] = locked_position;
+        assert_eq!(
+            validate_collect_clmm(&accounts),
+            Err(ValidationError::InvalidPda("locked_position"))
+        );
+    }
+
+    // Not a `LockedClmmPositionState`
+    let accounts = with_key(
+        roles,
+        collect_clmm_accounts(),
+        "locked_position",
+        collect_clmm_key("locked_position"),
+    );
+    assert_eq!(
+        validate_collect_clmm(&accounts),
+        Err(ValidationError::InvalidPda("locked_position"))
+    );
+}
+
+#[test]
+fn collect_clmm_fee_rejects_wrong_program_accounts() {
+    for (name, key) in [
+        ("clmm_program", CPMM_PROGRAM_ID),
+        ("token_program", TOKEN_2022_PROGRAM_ID),
+        ("token_program_2022", TOKEN_PROGRAM_ID),
+        ("memo_program", [9; 32]),
+    ] {
+        let accounts = with_key(
+            &CollectClmmFeeAndReward::ACCOUNTS,
+            collect_clmm_accounts(),
+            name,
+            key,
+        );
+        assert_eq!(
+            validate_collect_clmm(&accounts),
+            Err(ValidationError::IncorrectProgramId(name))
+        );
+    }
+}
+
+#[test]
+fn collect_clmm_fee_rejects_missing_signer_and_writable_flags() {
+    assert_rejects_missing_flags(
+        &CollectClmmFeeAndReward::ACCOUNTS,
+        collect_clmm_accounts,
+        validate_collect_clmm,
+    );
+}
+
+#[test]
+fn collect_clmm_fee_rejects_more_than_max_remaining_accounts() {
+    let mut accounts = collect_clmm_accounts();
+    for i in 0..MAX_REMAINING_ACCOUNTS {
+        let role = if i % 3 != 2 {
+            AccountRole::writable("remaining")
+        } else {
+            AccountRole::readonly("remaining")
+        };
+        accounts.push(TestAccount::for_role([i as u8 + 200; 32], &role));
+    }
+    assert_eq!(validate_collect_clmm(&accounts), Ok(()));
+
+    accounts.push(TestAccount::for_role(
+        [255; 32],
+        &AccountRole::readonly("remaining"),
+    ));
+    assert_eq!(
+        validate_collect_clmm(&accounts),
+        Err(ValidationError::TooManyAccounts("remaining_accounts"))
+    );
+}
+
+#[test]
+fn collect_clmm_fee_validates_a_pda_fee_nft_owner() {
+    let mut accounts = collect_clmm_accounts();
+    replace(
+        &CollectClmmFeeAndReward::ACCOUNTS,
+        &mut accounts,
+        AccountRole::readonly("fee_nft_owner"),
+        CALLER,
+    );
+    let infos = infos(&accounts);
+    let (collect, _) = CollectClmmFeeAndReward::from_accounts(&infos, 0).unwrap();
+
+    assert_eq!(
+        collect.validate(),
+        Err(ValidationError::NotSigner("fee_nft_owner"))
+    );
+    assert_eq!(collect.validate_for_pda_signers(&[&CALLER]), Ok(()));
+}
@@ -0,0 +1,200 @@
+mod common;
+
+use common::put;
+use pinocchio_raydium_locking_program::{
+    math::CpPoolAmounts,
+    raydium::clmm::{PersonalPositionState, PoolState},
+    states::LockedCpLiquidityState,
+    valuation::{
+        amount_0_for_liquidity, amount_1_for_liquidity, amounts_for_liquidity,
+        clmm_position_amounts, cp_locked_amounts, sqrt_price_x64_at_tick, MAX_SQRT_PRICE_X64,
+        MAX_TICK, MIN_SQRT_PRICE_X64, MIN_TICK,
+    },
+};
+use proptest::prelude::*;
+
+const Q64: u128 = 1 << 64;
+
+// Reference values from an arbitrary-precision port of the Raydium CLMM
+// `get_sqrt_price_at_tick` and `get_delta_amount_*_unsigned`.
+#[test]
+fn sqrt_price_at_tick_matches_known_vectors() {
+    let vectors = [
+        (MIN_TICK, MIN_SQRT_PRICE_X64),
+        (MAX_TICK, MAX_SQRT_PRICE_X64),
+        (0, Q64),
+        (1, 18_447_666_387_855_957_090),
+        (-1, 18_445_821_805_675_395_072),
+        (-28_861, 4_357_550_247_590_852_500),
+        (28_861, 78_090_291_009_052_504_402),
+        (-1_860, 16_808_631_456_335_284_239),
+        (-6_960, 13_025_451_886_045_027_112),
+        (4_080, 22_620_977_475_102_115_118),
+    ];
+    for (tick, sqrt_price_x64) in vectors {
+        assert_eq!(
+            sqrt_price_x64_at_tick(tick),
+            Some(sqrt_price_x64),
+            "tick {tick}"
+        );
+    }
+    assert_eq!(sqrt_price_x64_at_tick(MIN_TICK - 1), None);
+    assert_eq!(sqrt_price_x64_at_tick(MAX_TICK + 1), None);
+}
+
+#[test]
+fn amounts_for_liquidity_match_known_vectors() {
+    let at = |tick| sqrt_price_x64_at_tick(tick).unwrap();
+
+    // The Raydium `get_amounts_delta_signed_test` position
+    assert_eq!(
+        amounts_for_liquidity(-1_860, at(-1_860), -6_960, 4_080, 100_000),
+        Some((28_198, 20_508))
+    );
+
+    let liquidity = 1_000_000_000_000_000_000;
+    assert_eq!(
+        amounts_for_liquidity(-1_860, at(-1_860), -6_960, 4_080, liquidity),
+        Some((281_985_944_031_557_092, 205_086_575_450_573_695))
+    );
+    // Below the range only token 0, above it only token 1
+    assert_eq!(
+        amounts_for_liquidity(-10_000, at(-10_000), -6_960, 4_080, liquidity),
+        Some((600_736_920_764_311_913, 0))
+    );
+    assert_eq!(
+        amounts_for_liquidity(5_000, at(5_000), -6_960, 4_080, liquidity),
+        Some((0, 520_174_484_489_797_223))
+    );
+    // The pool price can sit anywhere within the current tick
+    assert_eq!(
+        amounts_for_liquidity(0, Q64 + 12_345, -60, 60, 123_456_789_012_345_678),
+        Some((369_796_904_808_755, 369_796_904_808_920))
+    );
+}
+
+#[test]
+fn amounts_for_liquidity_rejects_invalid_input() {
+    assert_eq!(amounts_for_liquidity(0, Q64, 60, 60, 1), None);
+    assert_eq!(amounts_for_liquidity(0, Q64, MIN_TICK - 1, 0, 1), None);
+    assert_eq!(amount_0_for_liquidity(0, Q64, 1), None);
+    // More than u64::MAX of either token
+    assert_eq!(
+        amount_1_for_liquidity(MIN_SQRT_PRICE_X64, MAX_SQRT_PRICE_X64, u128::MAX),
+        None
+    );
+    assert_eq!(
+        amount_0_for_liquidity(MIN_SQRT_PRICE_X64, MAX_SQRT_PRICE_X64, u128::MAX),
+        None
+    );
+}
+
+#[test]
+fn clmm_position_amounts_read_pool_and_position() {
+    let mut pool = [0u8; PoolState::LEN];
+    put(
+        &mut pool,
+        PoolState::SQRT_PRICE_X64_OFFSET,
+        &sqrt_price_x64_at_tick(-1_860).unwrap().to_le_bytes(),
+    );
+    put(
+        &mut pool,
+        PoolState::TICK_CURRENT_OFFSET,
+        &(-1_860i32).to_le_bytes(),
+    );
+
+    let mut position = [0u8; PersonalPositionState::LEN];
+    put(
+        &mut position,
+        PersonalPositionState::TICK_LOWER_INDEX_OFFSET,
+        &(-6_960i32).to_le_bytes(),
+    );
+    put(
+        &mut position,
+        PersonalPositionState::TICK_UPPER_INDEX_OFFSET,
+        &4_080i32.to_le_bytes(),
+    );
+    put(
+        &mut position,
+        PersonalPositionState::LIQUIDITY_OFFSET,
+        &100_000u128.to_le_bytes(),
+    );
+
+    let pool = unsafe { PoolState::from_bytes(&pool[8..]) };
+    let position = unsafe { PersonalPositionState::from_bytes(&position[8..]) };
+    assert_eq!(
+        clmm_position_amounts(pool, position),
+        Some((28_198, 20_508))
+    );
+}
+
+#[test]
+fn cp_locked_amounts_are_a_share_of_the_reserves() {
+    let data = LockedCpLiquidityState::builder()
+        .locked_lp_amount(2_500)
+        .to_account_bytes();
+    let state = unsafe { LockedCpLiquidityState::from_bytes(&data[8..]) };
+    let pool = CpPoolAmounts {
+        token_0_vault_amount: 1_000_003,
+        token_1_vault_amount: 40_000,
+        lp_supply: 10_000,
+    };
+    assert_eq!(cp_locked_amounts(state, &pool), Some((250_000, 10_000)));
+    assert_eq!(cp_locked_amounts(state, &CpPoolAmounts::default()), None);
+}
+
+proptest! {
+    #[test]
+    fn sqrt_price_tracks_floating_point(tick in MIN_TICK..=MAX_TICK) {
+        let sqrt_price_x64 = sqrt_price_x64_at_tick(tick).unwrap();
+        let expected = 1.0001f64.powf(tick as f64 / 2.0) * Q64 as f64;
+        let error = (sqrt_price_x64 as f64 - expected).abs() / expected;
+        prop_assert!(error < 1e-9, "tick {} error {}", tick, error);
+    }
+
+    #[test]
+    fn sqrt_price_increases_with_tick(tick in MIN_TICK..MAX_TICK) {
+        prop_assert!(sqrt_price_x64_at_tick(tick) < sqrt_price_x64_at_tick(tick + 1));
+    }
+
+    #[test]
+    fn amounts_are_continuous_at_range_bounds(
+        tick_lower in -100_000i32..100_000,
+        width in 1i32..50_000,
+        liquidity in 0u128..1 << 60,
+    ) {
+        let tick_upper = tick_lower + width;
+        let sqrt_lower = sqrt_price_x64_at_tick(tick_lower).unwrap();
+        let sqrt_upper = sqrt_price_x64_at_tick(tick_upper).unwrap();
+        let whole_range = amount_1_for_liquidity(sqrt_lower, sqrt_upper, liquidity);
+        prop_assume!(whole_range.is_some());
+
+        // Entering the range at its lower bound holds the same token 0 as below it
+        let amounts = |tick, sqrt_price_x64| {
+            amounts_for_liquidity(tick, sqrt_price_x64, tick_lower, tick_upper, liquidity)
+        };
+        prop_assert_eq!(amounts(tick_lower - 1, sqrt_lower - 1), amounts(tick_lower, sqrt_lower));
+
+        let above = amounts(tick_upper, sqrt_upper);
+        prop_assert_eq!(above.map(|(amount_0, _)| amount_0), Some(0));
+        prop_assert_eq!(above.map(|(_, amount_1)| amount_1), whole_range);
+    }
+}
+
+// The case proptest shrank `amounts_are_continuous_at_range_bounds` to before
+// it skipped ranges whose token 1 amount overflows `u64`
+#[test]
+fn amounts_above_a_range_are_none_when_token_1_overflows() {
+    let liquidity = 368_944_104_616_759_528_252_968;
+    let sqrt_lower = sqrt_price_x64_at_tick(0).unwrap();
+    let sqrt_upper = sqrt_price_x64_at_tick(1).unwrap();
+    assert_eq!(
+        amount_1_for_liquidity(sqrt_lower, sqrt_upper, liquidity),
+        None
+    );
+
+    let amounts =
+        |tick, sqrt_price_x64| amounts_for_liquidity(tick, sqrt_price_x64, 0, 1, liquidity);
+    assert_eq!(amounts(-1, sqrt_lower - 1), amounts(0, sqrt_lower));
+    assert_eq!(amounts(1, sqrt_upper), None);
+}